@@ -0,0 +1,174 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! An error type which bundles all errors of an iterator into one value
+//!
+//! Only available with the `alloc` feature. The `std::error::Error` implementation requires the
+//! `std` feature.
+
+use alloc::vec::{self, Vec};
+use core::fmt;
+use core::slice;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use collect::CollectResults;
+
+/// Number of errors which are rendered by the `Display` implementation of [`AggregateError`]
+const DISPLAYED_ERRORS: usize = 3;
+
+/// Multiple errors, bundled into a single error value
+///
+/// The `Display` implementation renders the number of errors and the first few of them. With the
+/// `std` feature, `AggregateError` implements `std::error::Error`, reporting the first error as
+/// its source.
+///
+/// ```
+/// use resiter::aggregate::AggregateError;
+///
+/// let err = AggregateError::from(vec!["a", "b", "c", "d", "e"]);
+///
+/// assert_eq!(err.errors().len(), 5);
+/// assert_eq!(err.to_string(), "5 errors occurred: a; b; c; and 2 more");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregateError<E> {
+    errors: Vec<E>,
+}
+
+impl<E> AggregateError<E> {
+    /// All bundled errors
+    #[inline]
+    pub fn errors(&self) -> &[E] {
+        &self.errors
+    }
+
+    /// Consume the aggregate and return the bundled errors
+    #[inline]
+    pub fn into_errors(self) -> Vec<E> {
+        self.errors
+    }
+}
+
+impl<E> From<Vec<E>> for AggregateError<E> {
+    #[inline]
+    fn from(errors: Vec<E>) -> Self {
+        AggregateError { errors }
+    }
+}
+
+impl<E> IntoIterator for AggregateError<E> {
+    type Item = E;
+    type IntoIter = vec::IntoIter<E>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a AggregateError<E> {
+    type Item = &'a E;
+    type IntoIter = slice::Iter<'a, E>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl<E> fmt::Display for AggregateError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let count = self.errors.len();
+        let noun = if count == 1 { "error" } else { "errors" };
+        write!(f, "{} {} occurred", count, noun)?;
+        for (n, e) in self.errors.iter().take(DISPLAYED_ERRORS).enumerate() {
+            let sep = if n == 0 { ": " } else { "; " };
+            write!(f, "{}{}", sep, e)?;
+        }
+        if count > DISPLAYED_ERRORS {
+            write!(f, "; and {} more", count - DISPLAYED_ERRORS)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> Error for AggregateError<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.errors.first().map(|e| e as &(dyn Error + 'static))
+    }
+}
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to collect all errors into an
+/// [`AggregateError`]
+pub trait CollectAggregate<O, E>: Sized {
+    /// Collect all `Ok` values, or all `Err` values bundled into an [`AggregateError`] if there
+    /// was at least one error
+    ///
+    /// Like [`CollectResults::collect_all_errors`], this drains the whole iterator.
+    ///
+    /// ```
+    /// use resiter::aggregate::CollectAggregate;
+    /// use std::str::FromStr;
+    ///
+    /// let res = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .collect_aggregate();
+    ///
+    /// assert_eq!(
+    ///     res.unwrap_err().to_string(),
+    ///     "2 errors occurred: invalid digit found in string; invalid digit found in string"
+    /// );
+    ///
+    /// let res = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .collect_aggregate();
+    ///
+    /// assert_eq!(res, Ok(vec![1, 2, 3]));
+    /// ```
+    fn collect_aggregate(self) -> Result<Vec<O>, AggregateError<E>>;
+}
+
+impl<I, O, E> CollectAggregate<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn collect_aggregate(self) -> Result<Vec<O>, AggregateError<E>> {
+        self.collect_all_errors().map_err(AggregateError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn test_aggregate_error_display_single() {
+        let err = AggregateError::from(vec!["broken"]);
+
+        assert_eq!(err.to_string(), "1 error occurred: broken");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_aggregate_error_source_is_first_error() {
+        let err = AggregateError::from(vec![std::fmt::Error, std::fmt::Error]);
+
+        assert!(err.source().is_some());
+    }
+}
@@ -4,6 +4,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform Oks and Errors.
 pub trait AndThen<O, E>: Sized {
     /// Map oks selectively, possibly converting them to errors
@@ -75,11 +78,23 @@ where
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct AndThenOk<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for AndThenOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AndThenOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, O2, F> Iterator for AndThenOk<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -99,14 +114,64 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, r| g(acc, r.and_then(&mut f)))
+    }
+}
+
+impl<I, O, E, O2, F> DoubleEndedIterator for AndThenOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            Some(Ok(o)) => Some((self.f)(o)),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+impl<I, O, E, O2, F> FusedIterator for AndThenOk<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+{
+}
+
+impl<I, O, E, O2, F> ExactSizeIterator for AndThenOk<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+{
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct AndThenErr<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for AndThenErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AndThenErr")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, E2, F> Iterator for AndThenErr<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -126,4 +191,82 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, r| g(acc, r.or_else(&mut f)))
+    }
+}
+
+impl<I, O, E, E2, F> DoubleEndedIterator for AndThenErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Result<O, E2>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            Some(Err(e)) => Some((self.f)(e)),
+            Some(Ok(o)) => Some(Ok(o)),
+            None => None,
+        }
+    }
+}
+
+impl<I, O, E, E2, F> FusedIterator for AndThenErr<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Result<O, E2>,
+{
+}
+
+impl<I, O, E, E2, F> ExactSizeIterator for AndThenErr<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Result<O, E2>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_and_then_exact_size() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let iter = v
+            .into_iter()
+            .and_then_ok(|i| if i > 1 { Err(i) } else { Ok(i) })
+            .and_then_err(|e| if e > 2 { Err(e) } else { Ok(e) });
+
+        assert_eq!(iter.len(), 3);
+    }
+
+    #[test]
+    fn test_and_then_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mapped: Vec<_> = v
+            .into_iter()
+            .and_then_ok(|i| if i < 2 { Ok(i) } else { Err(i) })
+            .rev()
+            .collect();
+
+        assert_eq!(mapped, vec![Err(3), Err(2), Ok(1)]);
+    }
+
+    #[test]
+    fn test_and_then_err_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3)];
+        let mapped: Vec<_> = v
+            .into_iter()
+            .and_then_err(|i| if i < 3 { Ok(i) } else { Err(i) })
+            .rev()
+            .collect();
+
+        assert_eq!(mapped, vec![Err(3), Ok(2), Ok(1)]);
+    }
 }
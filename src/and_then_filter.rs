@@ -4,6 +4,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform and map Oks and Errors.
 pub trait AndThenFilter<O, E>: Sized {
     /// Equivalent to [Iterator::filter_map] on all `Ok` values.
@@ -69,11 +72,23 @@ where
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct AndThenFilterOk<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for AndThenFilterOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AndThenFilterOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, F, O2> Iterator for AndThenFilterOk<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -96,6 +111,67 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(x) => match f(x) {
+                Some(r) => g(acc, r),
+                None => acc,
+            },
+            Err(e) => g(acc, Err(e)),
+        })
+    }
+}
+
+impl<I, O, E, F, O2> DoubleEndedIterator for AndThenFilterOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Option<Result<O2, E>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            return match self.iter.next_back() {
+                Some(Ok(x)) => match (self.f)(x) {
+                    Some(r) => Some(r),
+                    None => continue,
+                },
+                Some(Err(e)) => Some(Err(e)),
+                None => None,
+            };
+        }
+    }
+}
+
+impl<I, O, E, F, O2> FusedIterator for AndThenFilterOk<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Option<Result<O2, E>>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_and_then_filter_rev_and_hint() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(4)];
+        let iter = v.into_iter().and_then_filter(|i| match i {
+            3 => None,
+            4 => Some(Err(40)),
+            i => Some(Ok(i * 10)),
+        });
+        assert_eq!(iter.size_hint(), (0, Some(4)));
+
+        let res: Vec<_> = iter.rev().collect();
+        assert_eq!(res, vec![Err(40), Err(2), Ok(10)]);
     }
 }
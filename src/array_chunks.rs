@@ -0,0 +1,148 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::array;
+use core::fmt;
+use core::iter::{self, FusedIterator};
+use core::mem;
+
+/// Iterator over the `Ok` values which did not fill a whole chunk
+///
+/// See [`ArrayChunksOk::into_remainder`].
+pub type Remainder<O, const N: usize> = iter::Flatten<array::IntoIter<Option<O>, N>>;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to group `Ok` values into fixed-size arrays
+pub trait ArrayChunks<O, E>: Sized {
+    /// Group the `Ok` values into arrays of `N` elements, passing errors through as they appear
+    ///
+    /// Errors do not interrupt the chunk which is currently being filled. `Ok` values which do not
+    /// fill a whole chunk at the end are not yielded, but are available via
+    /// [`ArrayChunksOk::into_remainder`]. Unlike `chunks_ok`, this does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// ```
+    /// use resiter::array_chunks::ArrayChunks;
+    /// use std::str::FromStr;
+    ///
+    /// let mut chunks = ["1", "2", "a", "3", "4", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .array_chunks_ok::<2>();
+    ///
+    /// assert_eq!(chunks.next(), Some(Ok([1, 2])));
+    /// assert!(chunks.next().unwrap().is_err());
+    /// assert_eq!(chunks.next(), Some(Ok([3, 4])));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.into_remainder().collect::<Vec<_>>(), vec![5]);
+    /// ```
+    fn array_chunks_ok<const N: usize>(self) -> ArrayChunksOk<Self, O, N>;
+}
+
+impl<I, O, E> ArrayChunks<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn array_chunks_ok<const N: usize>(self) -> ArrayChunksOk<Self, O, N> {
+        assert!(N != 0, "chunk size must be non-zero");
+        ArrayChunksOk {
+            iter: self,
+            chunk: empty_chunk(),
+            filled: 0,
+        }
+    }
+}
+
+#[inline]
+fn empty_chunk<O, const N: usize>() -> [Option<O>; N] {
+    [(); N].map(|_| None)
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ArrayChunksOk<I, O, const N: usize> {
+    iter: I,
+    chunk: [Option<O>; N],
+    filled: usize,
+}
+
+impl<I, O, const N: usize> ArrayChunksOk<I, O, N> {
+    /// Consume the adapter and iterate over the buffered `Ok` values which did not fill a whole
+    /// chunk
+    #[inline]
+    pub fn into_remainder(self) -> Remainder<O, N> {
+        IntoIterator::into_iter(self.chunk).flatten()
+    }
+}
+
+impl<I, O, const N: usize> fmt::Debug for ArrayChunksOk<I, O, N>
+where
+    I: fmt::Debug,
+    O: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArrayChunksOk")
+            .field("iter", &self.iter)
+            .field("chunk", &&self.chunk[..self.filled])
+            .finish()
+    }
+}
+
+impl<I, O, E, const N: usize> Iterator for ArrayChunksOk<I, O, N>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<[O; N], E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(o) => {
+                    self.chunk[self.filled] = Some(o);
+                    self.filled += 1;
+                    if self.filled == N {
+                        self.filled = 0;
+                        let chunk = mem::replace(&mut self.chunk, empty_chunk());
+                        return Some(Ok(chunk.map(|o| o.expect("chunk is filled"))));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        // every error yields one item, so the fewest items are yielded if all are `Ok`
+        (lo.saturating_add(self.filled) / N, hi)
+    }
+}
+
+impl<I, O, E, const N: usize> FusedIterator for ArrayChunksOk<I, O, N> where
+    I: FusedIterator<Item = Result<O, E>>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_array_chunks_ok_remainder_after_errors() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(4), Ok(5), Err(6)];
+        let mut chunks = v.into_iter().array_chunks_ok::<3>();
+
+        assert_eq!(chunks.next(), Some(Err(2)));
+        assert_eq!(chunks.next(), Some(Ok([1, 3, 4])));
+        assert_eq!(chunks.next(), Some(Err(6)));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.into_remainder().collect::<Vec<_>>(), vec![5]);
+    }
+}
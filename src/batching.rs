@@ -0,0 +1,91 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to combine items with custom logic
+pub trait Batching<O, E>: Sized {
+    /// Repeatedly call a closure with mutable access to the underlying iterator, yielding what it
+    /// returns until it returns `None`
+    ///
+    /// The closure can consume as many items as it needs to build one `Result<B, E>`, which makes
+    /// this suitable for custom framing or grouping logic which may fail.
+    ///
+    /// ```
+    /// use resiter::batching::Batching;
+    ///
+    /// // sum up numbers until a 0 delimiter, a frame without delimiter is malformed
+    /// let frames: Vec<Result<usize, &str>> = vec![Ok(1), Ok(2), Ok(0), Ok(3), Ok(0), Ok(4)]
+    ///     .into_iter()
+    ///     .try_batching(|iter| {
+    ///         let mut sum = 0;
+    ///         let mut empty = true;
+    ///         for r in iter {
+    ///             match r {
+    ///                 Ok(0) => return Some(Ok(sum)),
+    ///                 Ok(i) => sum += i,
+    ///                 Err(e) => return Some(Err(e)),
+    ///             }
+    ///             empty = false;
+    ///         }
+    ///         if empty {
+    ///             None
+    ///         } else {
+    ///             Some(Err("missing delimiter"))
+    ///         }
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(frames, vec![Ok(3), Ok(3), Err("missing delimiter")]);
+    /// ```
+    fn try_batching<B, F>(self, _: F) -> TryBatching<Self, F>
+    where
+        F: FnMut(&mut Self) -> Option<Result<B, E>>;
+}
+
+impl<I, O, E> Batching<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn try_batching<B, F>(self, f: F) -> TryBatching<Self, F>
+    where
+        F: FnMut(&mut Self) -> Option<Result<B, E>>,
+    {
+        TryBatching { iter: self, f }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TryBatching<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for TryBatching<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TryBatching")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, B, F> Iterator for TryBatching<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut I) -> Option<Result<B, E>>,
+{
+    type Item = Result<B, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.f)(&mut self.iter)
+    }
+}
@@ -0,0 +1,157 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to temporarily treat it as an iterator
+/// over `O`
+pub trait CaptureErrors<O, E>: Sized {
+    /// Call `f` with an iterator over the `Ok` values, stopping at the first `Err`
+    ///
+    /// If an error was encountered, it is returned instead of the result of `f`. This allows
+    /// passing a fallible iterator to APIs that only accept infallible ones.
+    ///
+    /// ```
+    /// use resiter::capture::CaptureErrors;
+    /// use std::str::FromStr;
+    ///
+    /// let max = ["1", "5", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .capture_errors(|oks| oks.max());
+    ///
+    /// assert_eq!(max, Ok(Some(5)));
+    ///
+    /// let max = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .capture_errors(|oks| oks.max());
+    ///
+    /// assert!(max.is_err());
+    /// ```
+    fn capture_errors<F, R>(self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(Captured<'_, Self, E>) -> R;
+
+    /// Iterate over the `Ok` values, storing the first `Err` in `error` and stopping there
+    ///
+    /// If `error` already holds an error, the returned iterator is empty.
+    ///
+    /// ```
+    /// use resiter::capture::CaptureErrors;
+    /// use std::str::FromStr;
+    ///
+    /// let mut error = None;
+    /// let oks: Vec<usize> = ["1", "2", "a", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .until_err(&mut error)
+    ///     .collect();
+    ///
+    /// assert_eq!(oks, vec![1, 2]);
+    /// assert!(error.is_some());
+    /// ```
+    fn until_err(self, error: &mut Option<E>) -> Captured<'_, Self, E>;
+}
+
+impl<I, O, E> CaptureErrors<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn capture_errors<F, R>(self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(Captured<'_, Self, E>) -> R,
+    {
+        let mut error = None;
+        let r = f(Captured {
+            iter: self,
+            error: &mut error,
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(r),
+        }
+    }
+
+    #[inline]
+    fn until_err(self, error: &mut Option<E>) -> Captured<'_, Self, E> {
+        Captured { iter: self, error }
+    }
+}
+
+/// Iterator over the `Ok` values which stores the first `Err`
+///
+/// See [`CaptureErrors::capture_errors`] and [`CaptureErrors::until_err`].
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Captured<'a, I, E: 'a> {
+    iter: I,
+    error: &'a mut Option<E>,
+}
+
+impl<'a, I, E> fmt::Debug for Captured<'a, I, E>
+where
+    I: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Captured")
+            .field("iter", &self.iter)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<'a, I, O, E> Iterator for Captured<'a, I, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        match self.iter.next()? {
+            Ok(o) => Some(o),
+            Err(e) => {
+                *self.error = Some(e);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_some() {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<'a, I, O, E> FusedIterator for Captured<'a, I, E> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_errors_stops_at_first_error() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Err(4)];
+        let mut seen = Vec::new();
+        let res = v.into_iter().capture_errors(|mut oks| {
+            seen.extend(oks.by_ref());
+            assert_eq!(oks.next(), None);
+        });
+
+        assert_eq!(res, Err(2));
+        assert_eq!(seen, vec![1]);
+    }
+}
@@ -0,0 +1,445 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions to access the `source()` chain of the errors in an `Iterator<Item = Result<O, E>>`
+//!
+//! Only available with the `std` feature.
+
+use alloc::vec::{self, Vec};
+use core::fmt;
+use core::iter::FusedIterator;
+use std::error::Error;
+
+use util::{and_then_or_clear, flatten_size_hint};
+
+/// Iterate over `err` and all of its transitive sources, starting with `err` itself
+///
+/// ```
+/// use resiter::causes::chain;
+/// use std::error::Error;
+/// use std::fmt;
+///
+/// #[derive(Debug)]
+/// struct Outer(std::num::ParseIntError);
+///
+/// impl fmt::Display for Outer {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "failed to read config")
+///     }
+/// }
+///
+/// impl Error for Outer {
+///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// let err = Outer("a".parse::<usize>().unwrap_err());
+/// let messages: Vec<String> = chain(&err).map(|e| e.to_string()).collect();
+///
+/// assert_eq!(
+///     messages,
+///     vec!["failed to read config", "invalid digit found in string"]
+/// );
+/// ```
+#[inline]
+pub fn chain<'a>(err: &'a (dyn Error + 'static)) -> Chain<'a> {
+    Chain { next: Some(err) }
+}
+
+/// Iterator over an error and its sources, see [`chain`]
+#[derive(Clone, Debug)]
+pub struct Chain<'a> {
+    next: Option<&'a (dyn Error + 'static)>,
+}
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let err = self.next?;
+        self.next = err.source();
+        Some(err)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_some() {
+            (1, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<'a> FusedIterator for Chain<'a> {}
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to look at the causes of the errors
+pub trait ErrorCauses<O, E>: Sized {
+    /// Expand every `Err` item into one `Err` per error in its `source()` chain
+    ///
+    /// `f` is called for the error itself and each of its sources, starting with the outermost
+    /// one. Since the sources are borrowed from the error, `f` has to turn them into an owned
+    /// value.
+    ///
+    /// ```
+    /// use resiter::causes::ErrorCauses;
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct Outer(std::num::ParseIntError);
+    ///
+    /// impl fmt::Display for Outer {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "failed to read config")
+    ///     }
+    /// }
+    ///
+    /// impl Error for Outer {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let res: Vec<Result<usize, String>> = ["1", "a"]
+    ///     .iter()
+    ///     .map(|txt| txt.parse().map_err(Outer))
+    ///     .flat_map_err_sources(|e| e.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(res.len(), 3);
+    /// assert_eq!(res[0], Ok(1));
+    /// assert_eq!(res[1], Err("failed to read config".to_string()));
+    /// assert_eq!(res[2], Err("invalid digit found in string".to_string()));
+    /// ```
+    fn flat_map_err_sources<F, E2>(self, _: F) -> FlatMapErrSources<Self, F, E2>
+    where
+        E: Error + 'static,
+        F: FnMut(&(dyn Error + 'static)) -> E2;
+
+    /// Replace every `Err` item with the result of calling `f` on its deepest source
+    ///
+    /// Errors without a source are passed to `f` themselves.
+    ///
+    /// ```
+    /// use resiter::causes::ErrorCauses;
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct Outer(std::num::ParseIntError);
+    ///
+    /// impl fmt::Display for Outer {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         write!(f, "failed to read config")
+    ///     }
+    /// }
+    ///
+    /// impl Error for Outer {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> {
+    ///         Some(&self.0)
+    ///     }
+    /// }
+    ///
+    /// let res: Vec<Result<usize, String>> = ["1", "a"]
+    ///     .iter()
+    ///     .map(|txt| txt.parse().map_err(Outer))
+    ///     .map_err_root_cause(|e| e.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     res,
+    ///     vec![Ok(1), Err("invalid digit found in string".to_string())]
+    /// );
+    /// ```
+    fn map_err_root_cause<F, E2>(self, _: F) -> MapErrRootCause<Self, F>
+    where
+        E: Error + 'static,
+        F: FnMut(&(dyn Error + 'static)) -> E2;
+}
+
+impl<I, O, E> ErrorCauses<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn flat_map_err_sources<F, E2>(self, f: F) -> FlatMapErrSources<Self, F, E2>
+    where
+        E: Error + 'static,
+        F: FnMut(&(dyn Error + 'static)) -> E2,
+    {
+        FlatMapErrSources {
+            frontiter: None,
+            backiter: None,
+            iter: self,
+            f,
+        }
+    }
+
+    #[inline]
+    fn map_err_root_cause<F, E2>(self, f: F) -> MapErrRootCause<Self, F>
+    where
+        E: Error + 'static,
+        F: FnMut(&(dyn Error + 'static)) -> E2,
+    {
+        MapErrRootCause { iter: self, f }
+    }
+}
+
+/// Map `err` and all of its sources with `f`
+fn map_chain<E, F, E2>(err: &E, f: &mut F) -> vec::IntoIter<E2>
+where
+    E: Error + 'static,
+    F: FnMut(&(dyn Error + 'static)) -> E2,
+{
+    chain(err).map(f).collect::<Vec<_>>().into_iter()
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct FlatMapErrSources<I, F, E2> {
+    frontiter: Option<vec::IntoIter<E2>>,
+    backiter: Option<vec::IntoIter<E2>>,
+    iter: I,
+    f: F,
+}
+
+impl<I, F, E2> fmt::Debug for FlatMapErrSources<I, F, E2>
+where
+    I: fmt::Debug,
+    E2: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlatMapErrSources")
+            .field("iter", &self.iter)
+            .field("frontiter", &self.frontiter)
+            .field("backiter", &self.backiter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, E2> Iterator for FlatMapErrSources<I, F, E2>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: Error + 'static,
+    F: FnMut(&(dyn Error + 'static)) -> E2,
+{
+    type Item = Result<O, E2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.frontiter {
+                if let elt @ Some(_) = inner.next() {
+                    return elt.map(Err);
+                }
+                self.frontiter = None;
+            }
+            match self.iter.next() {
+                None => return and_then_or_clear(&mut self.backiter, Iterator::next).map(Err),
+                Some(Err(e)) => {
+                    self.frontiter = Some(map_chain(&e, &mut self.f));
+                }
+                Some(Ok(o)) => return Some(Ok(o)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = flatten_size_hint(
+            self.frontiter.as_ref(),
+            self.backiter.as_ref(),
+            self.iter.size_hint(),
+        );
+        // every item of the underlying iterator yields at least one item
+        (lo.saturating_add(self.iter.size_hint().0), hi)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        let mut acc = init;
+        if let Some(front) = self.frontiter {
+            acc = front.fold(acc, |acc, x| g(acc, Err(x)));
+        }
+        acc = self.iter.fold(acc, |acc, r| match r {
+            Err(e) => map_chain(&e, &mut f).fold(acc, |acc, x| g(acc, Err(x))),
+            Ok(o) => g(acc, Ok(o)),
+        });
+        if let Some(back) = self.backiter {
+            acc = back.fold(acc, |acc, x| g(acc, Err(x)));
+        }
+        acc
+    }
+}
+
+impl<I, O, E, F, E2> DoubleEndedIterator for FlatMapErrSources<I, F, E2>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    E: Error + 'static,
+    F: FnMut(&(dyn Error + 'static)) -> E2,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.backiter {
+                if let elt @ Some(_) = inner.next_back() {
+                    return elt.map(Err);
+                }
+                self.backiter = None;
+            }
+            match self.iter.next_back() {
+                None => {
+                    return and_then_or_clear(&mut self.frontiter, DoubleEndedIterator::next_back)
+                        .map(Err)
+                }
+                Some(Err(e)) => {
+                    self.backiter = Some(map_chain(&e, &mut self.f));
+                }
+                Some(Ok(o)) => return Some(Ok(o)),
+            }
+        }
+    }
+}
+
+impl<I, O, E, F, E2> FusedIterator for FlatMapErrSources<I, F, E2>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    E: Error + 'static,
+    F: FnMut(&(dyn Error + 'static)) -> E2,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapErrRootCause<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for MapErrRootCause<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapErrRootCause")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+/// Call `f` with the deepest source of `err`
+fn map_root_cause<E, F, E2>(err: E, f: &mut F) -> E2
+where
+    E: Error + 'static,
+    F: FnMut(&(dyn Error + 'static)) -> E2,
+{
+    // a chain always yields at least the error itself
+    f(chain(&err).last().unwrap_or(&err))
+}
+
+impl<I, O, E, F, E2> Iterator for MapErrRootCause<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: Error + 'static,
+    F: FnMut(&(dyn Error + 'static)) -> E2,
+{
+    type Item = Result<O, E2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter
+            .next()
+            .map(|r| r.map_err(|e| map_root_cause(e, f)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| {
+            g(acc, r.map_err(|e| map_root_cause(e, &mut f)))
+        })
+    }
+}
+
+impl<I, O, E, F, E2> DoubleEndedIterator for MapErrRootCause<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    E: Error + 'static,
+    F: FnMut(&(dyn Error + 'static)) -> E2,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter
+            .next_back()
+            .map(|r| r.map_err(|e| map_root_cause(e, f)))
+    }
+}
+
+impl<I, O, E, F, E2> FusedIterator for MapErrRootCause<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    E: Error + 'static,
+    F: FnMut(&(dyn Error + 'static)) -> E2,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use std::string::{String, ToString};
+
+    #[derive(Debug)]
+    struct Nested(&'static str, Option<Box<Nested>>);
+
+    impl fmt::Display for Nested {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl Error for Nested {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.1.as_ref().map(|e| &**e as &(dyn Error + 'static))
+        }
+    }
+
+    fn nested(outer: &'static str, inner: &'static str) -> Nested {
+        Nested(outer, Some(Box::new(Nested(inner, None))))
+    }
+
+    #[test]
+    fn test_flat_map_err_sources_rev_and_hint() {
+        let v: Vec<Result<usize, Nested>> =
+            vec![Err(nested("a", "b")), Ok(1), Err(nested("c", "d"))];
+        let mut iter = v.into_iter().flat_map_err_sources(|e| e.to_string());
+        assert_eq!(iter.size_hint(), (3, None));
+
+        let res: Vec<Result<usize, String>> = iter.by_ref().rev().collect();
+        let expected: Vec<Result<usize, String>> = vec![
+            Err("d".to_string()),
+            Err("c".to_string()),
+            Ok(1),
+            Err("b".to_string()),
+            Err("a".to_string()),
+        ];
+        assert_eq!(res, expected);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
@@ -0,0 +1,1071 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Consumers and adapters which collect `Ok` and `Err` values into allocated containers.
+//!
+//! Only available with the `alloc` feature.
+
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec::{self, Vec};
+use core::cmp::{self, Ordering};
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+use core::mem;
+
+use capture::CaptureErrors;
+use sink::ErrorSink;
+
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to collect `Ok` and `Err` values.
+pub trait CollectResults<O, E>: Sized {
+    /// Drain the iterator and return all `Ok` values and all `Err` values in one pass
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let (oks, errs) = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .partition_results();
+    ///
+    /// assert_eq!(oks, vec![1, 2, 4]);
+    /// assert_eq!(errs.len(), 2);
+    /// ```
+    fn partition_results(self) -> (Vec<O>, Vec<E>);
+
+    /// Collect all `Ok` values, or all `Err` values if there was at least one error
+    ///
+    /// Other than `collect::<Result<Vec<_>, _>>()`, this does not stop at the first error but
+    /// drains the whole iterator, so every error is reported.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let res = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .collect_all_errors();
+    ///
+    /// assert_eq!(res.unwrap_err().len(), 2);
+    ///
+    /// let res = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .collect_all_errors();
+    ///
+    /// assert_eq!(res, Ok(vec![1, 2, 3]));
+    /// ```
+    fn collect_all_errors(self) -> Result<Vec<O>, Vec<E>>;
+
+    /// Collect all `Ok` and `Err` values, aborting once more than `max_errors` errors occurred
+    ///
+    /// If the error budget is exceeded, the iterator is not consumed any further and all errors
+    /// seen so far are returned. Otherwise all `Ok` values are returned together with the
+    /// tolerated errors.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let (oks, errs) = ["1", "a", "3", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .collect_with_max_errors(1)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(oks, vec![1, 3, 4]);
+    /// assert_eq!(errs.len(), 1);
+    ///
+    /// let mut iter = ["1", "a", "b", "4"].iter().map(|txt| usize::from_str(txt));
+    /// let res = iter.by_ref().collect_with_max_errors(1);
+    ///
+    /// assert_eq!(res.unwrap_err().len(), 2);
+    /// assert_eq!(iter.next(), Some(Ok(4)));
+    /// ```
+    fn collect_with_max_errors(self, max_errors: usize) -> Result<(Vec<O>, Vec<E>), Vec<E>>;
+
+    /// Return the first `Ok` value, or all `Err` values if there is none
+    ///
+    /// The iterator is only drained up to the first `Ok` value, and the errors before it are
+    /// discarded. If there is no `Ok` value, all errors are returned, which is an empty `Vec` for
+    /// an empty iterator.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let first = ["a", "2", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .first_ok();
+    ///
+    /// assert_eq!(first, Ok(2));
+    ///
+    /// let first = ["a", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .first_ok();
+    ///
+    /// assert_eq!(first.unwrap_err().len(), 2);
+    /// ```
+    fn first_ok(self) -> Result<O, Vec<E>>;
+
+    /// Drain the iterator, grouping all `Ok` values by a key and collecting all `Err` values
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let (groups, errs) = ["1", "2", "a", "4", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .group_oks_by_key(|i| i % 2 == 0);
+    ///
+    /// assert_eq!(groups[&true], vec![2, 4]);
+    /// assert_eq!(groups[&false], vec![1, 5]);
+    /// assert_eq!(errs.len(), 1);
+    /// ```
+    fn group_oks_by_key<K, F>(self, _: F) -> (BTreeMap<K, Vec<O>>, Vec<E>)
+    where
+        K: Ord,
+        F: FnMut(&O) -> K;
+
+    /// Drain the iterator, collecting all `Ok` values and grouping all `Err` values by a key
+    ///
+    /// Only available with the `std` feature.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let items = vec![
+    ///     Ok("a"),
+    ///     Err(Error::from(ErrorKind::NotFound)),
+    ///     Err(Error::from(ErrorKind::PermissionDenied)),
+    ///     Ok("b"),
+    ///     Err(Error::from(ErrorKind::NotFound)),
+    /// ];
+    ///
+    /// let (oks, errs) = items.into_iter().group_errors_by(Error::kind);
+    ///
+    /// assert_eq!(oks, vec!["a", "b"]);
+    /// assert_eq!(errs[&ErrorKind::NotFound].len(), 2);
+    /// assert_eq!(errs[&ErrorKind::PermissionDenied].len(), 1);
+    /// ```
+    #[cfg(feature = "std")]
+    fn group_errors_by<K, F>(self, _: F) -> (Vec<O>, HashMap<K, Vec<E>>)
+    where
+        K: Hash + Eq,
+        F: FnMut(&E) -> K;
+
+    /// Group all `Ok` values by a key, stopping at the first `Err`
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let groups = ["1", "2", "3", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .try_group_oks_by_key(|i| i % 2 == 0)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(groups[&true], vec![2, 4]);
+    ///
+    /// let res = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .try_group_oks_by_key(|i| i % 2 == 0);
+    ///
+    /// assert!(res.is_err());
+    /// ```
+    fn try_group_oks_by_key<K, F>(self, _: F) -> Result<BTreeMap<K, Vec<O>>, E>
+    where
+        K: Ord,
+        F: FnMut(&O) -> K;
+
+    /// Collect consecutive `Ok` values into `Vec`s of `size` elements, while yielding `Err` items
+    /// as soon as they are encountered
+    ///
+    /// Errors do not interrupt the chunk which is currently being filled. The last chunk may be
+    /// shorter than `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let chunks: Vec<_> = ["1", "2", "a", "3", "4", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .chunks_ok(2)
+    ///     .collect();
+    ///
+    /// assert_eq!(chunks.len(), 4);
+    /// assert_eq!(chunks[0], Ok(vec![1, 2]));
+    /// assert!(chunks[1].is_err());
+    /// assert_eq!(chunks[2], Ok(vec![3, 4]));
+    /// assert_eq!(chunks[3], Ok(vec![5]));
+    /// ```
+    fn chunks_ok(self, size: usize) -> ChunksOk<Self, O>;
+
+    /// Yield overlapping windows over the last `size` consecutive `Ok` values, while yielding
+    /// `Err` items as soon as they are encountered
+    ///
+    /// A window is yielded for every `Ok` value once `size` values were seen. By default, errors
+    /// do not interrupt the window, see [`WindowsOk::reset_on_err`] to start over after every
+    /// error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let windows: Vec<_> = ["1", "2", "a", "3", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .windows_ok(2)
+    ///     .collect();
+    ///
+    /// assert_eq!(windows.len(), 4);
+    /// assert_eq!(windows[0], Ok(vec![1, 2]));
+    /// assert!(windows[1].is_err());
+    /// assert_eq!(windows[2], Ok(vec![2, 3]));
+    /// assert_eq!(windows[3], Ok(vec![3, 4]));
+    /// ```
+    fn windows_ok(self, size: usize) -> WindowsOk<Self, O>
+    where
+        O: Clone;
+
+    /// Drain the iterator, extending `target` with all `Ok` values and returning all `Err` values
+    ///
+    /// This allows reusing an already allocated collection across several batches.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let mut oks = vec![0];
+    /// let errs = ["1", "2", "a", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .extend_oks_into(&mut oks);
+    ///
+    /// assert_eq!(oks, vec![0, 1, 2, 4]);
+    /// assert_eq!(errs.len(), 1);
+    /// ```
+    fn extend_oks_into<C>(self, target: &mut C) -> Vec<E>
+    where
+        C: Extend<O>;
+
+    /// Extend `target` with the `Ok` values, stopping at the first `Err`
+    ///
+    /// The `Ok` values before the error are still added to `target`.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let mut oks = vec![0];
+    /// let res = ["1", "2", "a", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .try_extend_oks_into(&mut oks);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(oks, vec![0, 1, 2]);
+    /// ```
+    fn try_extend_oks_into<C>(self, target: &mut C) -> Result<(), E>
+    where
+        C: Extend<O>;
+
+    /// Yield all `Ok` values in sorted order, after all `Err` items
+    ///
+    /// The `Ok` values are buffered until the iterator is exhausted, while errors are yielded as
+    /// soon as they are encountered. Use [`SortedOks::errors_last`] to yield the errors after the
+    /// sorted values instead. The sort is stable.
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let sorted: Vec<_> = ["3", "1", "a", "2"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .sorted_oks()
+    ///     .collect();
+    ///
+    /// assert!(sorted[0].is_err());
+    /// assert_eq!(sorted[1..], [Ok(1), Ok(2), Ok(3)]);
+    ///
+    /// let sorted: Vec<_> = ["3", "1", "a", "2"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .sorted_oks()
+    ///     .errors_last()
+    ///     .collect();
+    ///
+    /// assert_eq!(sorted[..3], [Ok(1), Ok(2), Ok(3)]);
+    /// assert!(sorted[3].is_err());
+    /// ```
+    fn sorted_oks(self) -> SortedOksByOrd<Self, O, E>
+    where
+        O: Ord;
+
+    /// Yield all `Ok` values sorted with the comparator `cmp`, after all `Err` items
+    ///
+    /// See [`CollectResults::sorted_oks`].
+    ///
+    /// ```
+    /// use resiter::collect::CollectResults;
+    /// use std::str::FromStr;
+    ///
+    /// let sorted: Vec<_> = ["3", "1", "a", "2"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .sorted_oks_by(|a, b| b.cmp(a))
+    ///     .collect();
+    ///
+    /// assert!(sorted[0].is_err());
+    /// assert_eq!(sorted[1..], [Ok(3), Ok(2), Ok(1)]);
+    /// ```
+    fn sorted_oks_by<F>(self, cmp: F) -> SortedOks<Self, O, E, F>
+    where
+        F: FnMut(&O, &O) -> Ordering;
+}
+
+impl<I, O, E> CollectResults<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    fn partition_results(self) -> (Vec<O>, Vec<E>) {
+        self.collect::<Partition<O, E>>().into_inner()
+    }
+
+    fn collect_all_errors(self) -> Result<Vec<O>, Vec<E>> {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for res in self {
+            match res {
+                Ok(o) if errs.is_empty() => oks.push(o),
+                Ok(_) => {}
+                Err(e) => errs.push(e),
+            }
+        }
+
+        if errs.is_empty() {
+            Ok(oks)
+        } else {
+            Err(errs)
+        }
+    }
+
+    fn collect_with_max_errors(self, max_errors: usize) -> Result<(Vec<O>, Vec<E>), Vec<E>> {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for res in self {
+            match res {
+                Ok(o) => oks.push(o),
+                Err(e) => {
+                    errs.push(e);
+                    if errs.len() > max_errors {
+                        return Err(errs);
+                    }
+                }
+            }
+        }
+        Ok((oks, errs))
+    }
+
+    fn first_ok(self) -> Result<O, Vec<E>> {
+        let mut errs = Vec::new();
+        for res in self {
+            match res {
+                Ok(o) => return Ok(o),
+                Err(e) => errs.push(e),
+            }
+        }
+        Err(errs)
+    }
+
+    fn group_oks_by_key<K, F>(self, mut f: F) -> (BTreeMap<K, Vec<O>>, Vec<E>)
+    where
+        K: Ord,
+        F: FnMut(&O) -> K,
+    {
+        let mut groups = BTreeMap::new();
+        let mut errs = Vec::new();
+        for res in self {
+            match res {
+                Ok(o) => groups.entry(f(&o)).or_insert_with(Vec::new).push(o),
+                Err(e) => errs.push(e),
+            }
+        }
+        (groups, errs)
+    }
+
+    #[cfg(feature = "std")]
+    fn group_errors_by<K, F>(self, mut f: F) -> (Vec<O>, HashMap<K, Vec<E>>)
+    where
+        K: Hash + Eq,
+        F: FnMut(&E) -> K,
+    {
+        let mut oks = Vec::new();
+        let mut groups = HashMap::new();
+        for res in self {
+            match res {
+                Ok(o) => oks.push(o),
+                Err(e) => groups.entry(f(&e)).or_insert_with(Vec::new).push(e),
+            }
+        }
+        (oks, groups)
+    }
+
+    fn try_group_oks_by_key<K, F>(self, mut f: F) -> Result<BTreeMap<K, Vec<O>>, E>
+    where
+        K: Ord,
+        F: FnMut(&O) -> K,
+    {
+        let mut groups = BTreeMap::new();
+        for res in self {
+            let o = res?;
+            groups.entry(f(&o)).or_insert_with(Vec::new).push(o);
+        }
+        Ok(groups)
+    }
+
+    #[inline]
+    fn chunks_ok(self, size: usize) -> ChunksOk<Self, O> {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunksOk {
+            iter: self,
+            size,
+            chunk: Vec::new(),
+        }
+    }
+
+    #[inline]
+    fn windows_ok(self, size: usize) -> WindowsOk<Self, O>
+    where
+        O: Clone,
+    {
+        assert!(size != 0, "window size must be non-zero");
+        WindowsOk {
+            iter: self,
+            size,
+            window: VecDeque::new(),
+            reset_on_err: false,
+        }
+    }
+
+    fn extend_oks_into<C>(self, target: &mut C) -> Vec<E>
+    where
+        C: Extend<O>,
+    {
+        let mut errs = Vec::new();
+        target.extend(self.errors_to(&mut errs));
+        errs
+    }
+
+    fn try_extend_oks_into<C>(self, target: &mut C) -> Result<(), E>
+    where
+        C: Extend<O>,
+    {
+        self.capture_errors(|oks| target.extend(oks))
+    }
+
+    #[inline]
+    fn sorted_oks(self) -> SortedOksByOrd<Self, O, E>
+    where
+        O: Ord,
+    {
+        self.sorted_oks_by(Ord::cmp)
+    }
+
+    #[inline]
+    fn sorted_oks_by<F>(self, cmp: F) -> SortedOks<Self, O, E, F>
+    where
+        F: FnMut(&O, &O) -> Ordering,
+    {
+        SortedOks {
+            iter: self,
+            cmp,
+            oks: Vec::new(),
+            errs: None,
+            sorted: None,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ChunksOk<I, O> {
+    iter: I,
+    size: usize,
+    chunk: Vec<O>,
+}
+
+impl<I, O> fmt::Debug for ChunksOk<I, O>
+where
+    I: fmt::Debug,
+    O: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ChunksOk")
+            .field("iter", &self.iter)
+            .field("size", &self.size)
+            .field("chunk", &self.chunk)
+            .finish()
+    }
+}
+
+impl<I, O, E> Iterator for ChunksOk<I, O>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<Vec<O>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some(Ok(o)) => {
+                    if self.chunk.is_empty() {
+                        // `size` may be far larger than the number of items, so only reserve
+                        // as much as the iterator promises to yield
+                        let expected = self.iter.size_hint().0.saturating_add(1);
+                        self.chunk.reserve_exact(cmp::min(self.size, expected));
+                    }
+                    self.chunk.push(o);
+                    if self.chunk.len() == self.size {
+                        return Some(Ok(mem::take(&mut self.chunk)));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None if self.chunk.is_empty() => return None,
+                None => return Some(Ok(mem::take(&mut self.chunk))),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let buffered = self.chunk.len();
+        // every error yields one item, so the fewest items are yielded if all are `Ok`
+        let lo = lo.saturating_add(buffered).saturating_add(self.size - 1) / self.size;
+        let hi = hi.and_then(|hi| hi.checked_add((buffered != 0) as usize));
+        (lo, hi)
+    }
+}
+
+impl<I, O, E> FusedIterator for ChunksOk<I, O> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct WindowsOk<I, O> {
+    iter: I,
+    size: usize,
+    window: VecDeque<O>,
+    reset_on_err: bool,
+}
+
+impl<I, O> WindowsOk<I, O> {
+    /// Discard the current window on every `Err` item, so no window spans an error
+    #[inline]
+    pub fn reset_on_err(mut self) -> Self {
+        self.reset_on_err = true;
+        self
+    }
+}
+
+impl<I, O> fmt::Debug for WindowsOk<I, O>
+where
+    I: fmt::Debug,
+    O: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WindowsOk")
+            .field("iter", &self.iter)
+            .field("size", &self.size)
+            .field("window", &self.window)
+            .field("reset_on_err", &self.reset_on_err)
+            .finish()
+    }
+}
+
+impl<I, O, E> Iterator for WindowsOk<I, O>
+where
+    I: Iterator<Item = Result<O, E>>,
+    O: Clone,
+{
+    type Item = Result<Vec<O>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(o) => {
+                    if self.window.len() == self.size {
+                        self.window.pop_front();
+                    }
+                    self.window.push_back(o);
+                    if self.window.len() == self.size {
+                        return Some(Ok(self.window.iter().cloned().collect()));
+                    }
+                }
+                Err(e) => {
+                    if self.reset_on_err {
+                        self.window.clear();
+                    }
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let lo = if self.reset_on_err {
+            0
+        } else {
+            // every error yields one item, so the fewest items are yielded if all are `Ok`
+            let buffered = self.window.len().min(self.size - 1);
+            lo.saturating_add(buffered)
+                .saturating_add(1)
+                .saturating_sub(self.size)
+        };
+        (lo, hi)
+    }
+}
+
+impl<I, O, E> FusedIterator for WindowsOk<I, O>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    O: Clone,
+{
+}
+
+/// [`SortedOks`] using the `Ord` implementation of the `Ok` values, see
+/// [`CollectResults::sorted_oks`]
+pub type SortedOksByOrd<I, O, E> = SortedOks<I, O, E, fn(&O, &O) -> Ordering>;
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SortedOks<I, O, E, F> {
+    iter: I,
+    cmp: F,
+    oks: Vec<O>,
+    /// Errors held back until the end, if requested via `errors_last`
+    errs: Option<Vec<E>>,
+    /// The sorted `Ok` values and held back errors, once `iter` is exhausted
+    sorted: Option<(vec::IntoIter<O>, vec::IntoIter<E>)>,
+}
+
+impl<I, O, E, F> SortedOks<I, O, E, F> {
+    /// Hold back all `Err` items and yield them after the sorted `Ok` values
+    ///
+    /// Errors which were already yielded are not affected.
+    #[inline]
+    pub fn errors_last(mut self) -> Self {
+        if self.errs.is_none() {
+            self.errs = Some(Vec::new());
+        }
+        self
+    }
+}
+
+impl<I, O, E, F> fmt::Debug for SortedOks<I, O, E, F>
+where
+    I: fmt::Debug,
+    O: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SortedOks")
+            .field("iter", &self.iter)
+            .field("oks", &self.oks)
+            .field("errs", &self.errs)
+            .field("sorted", &self.sorted)
+            .finish()
+    }
+}
+
+impl<I, O, E, F> Iterator for SortedOks<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&O, &O) -> Ordering,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.sorted.is_none() {
+            for r in self.iter.by_ref() {
+                match r {
+                    Ok(o) => self.oks.push(o),
+                    Err(e) => match self.errs {
+                        Some(ref mut errs) => errs.push(e),
+                        None => return Some(Err(e)),
+                    },
+                }
+            }
+
+            let mut oks = mem::take(&mut self.oks);
+            oks.sort_by(&mut self.cmp);
+            let errs = self.errs.take().unwrap_or_default();
+            self.sorted = Some((oks.into_iter(), errs.into_iter()));
+        }
+
+        let (oks, errs) = self.sorted.as_mut()?;
+        oks.next().map(Ok).or_else(|| errs.next().map(Err))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.sorted {
+            Some((ref oks, ref errs)) => {
+                let len = oks.len() + errs.len();
+                (len, Some(len))
+            }
+            None => {
+                let buffered = self.oks.len() + self.errs.as_ref().map_or(0, Vec::len);
+                let (lo, hi) = self.iter.size_hint();
+                (
+                    lo.saturating_add(buffered),
+                    hi.and_then(|hi| hi.checked_add(buffered)),
+                )
+            }
+        }
+    }
+}
+
+impl<I, O, E, F> FusedIterator for SortedOks<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&O, &O) -> Ordering,
+{
+}
+
+/// All `Ok` and all `Err` values of an `Iterator<Item = Result<O, E>>`, kept in two buckets
+///
+/// Other than [`CollectResults::partition_results`], this can be filled incrementally via
+/// `Extend` and used wherever a `FromIterator` is expected.
+///
+/// ```
+/// use resiter::collect::Partition;
+/// use std::str::FromStr;
+///
+/// let mut partition: Partition<_, _> = ["1", "a", "3"]
+///     .iter()
+///     .map(|txt| usize::from_str(txt))
+///     .collect();
+///
+/// partition.extend(["4", "b"].iter().map(|txt| usize::from_str(txt)));
+///
+/// assert_eq!(partition.oks(), &[1, 3, 4]);
+/// assert_eq!(partition.errs().len(), 2);
+///
+/// let (oks, errs) = partition.into_inner();
+/// assert_eq!(oks, vec![1, 3, 4]);
+/// assert_eq!(errs.len(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Partition<O, E> {
+    oks: Vec<O>,
+    errs: Vec<E>,
+}
+
+impl<O, E> Partition<O, E> {
+    /// Create an empty partition
+    #[inline]
+    pub fn new() -> Self {
+        Partition {
+            oks: Vec::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// All collected `Ok` values
+    #[inline]
+    pub fn oks(&self) -> &[O] {
+        &self.oks
+    }
+
+    /// All collected `Err` values
+    #[inline]
+    pub fn errs(&self) -> &[E] {
+        &self.errs
+    }
+
+    /// Consume the partition and return the `Ok` values and the `Err` values
+    #[inline]
+    pub fn into_inner(self) -> (Vec<O>, Vec<E>) {
+        (self.oks, self.errs)
+    }
+
+    /// Whether no errors were collected
+    #[inline]
+    pub fn is_all_ok(&self) -> bool {
+        self.errs.is_empty()
+    }
+}
+
+impl<O, E> Default for Partition<O, E> {
+    #[inline]
+    fn default() -> Self {
+        Partition::new()
+    }
+}
+
+impl<O, E> Extend<Result<O, E>> for Partition<O, E> {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = Result<O, E>>,
+    {
+        for r in iter {
+            match r {
+                Ok(o) => self.oks.push(o),
+                Err(e) => self.errs.push(e),
+            }
+        }
+    }
+}
+
+impl<O, E> FromIterator<Result<O, E>> for Partition<O, E> {
+    #[inline]
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = Result<O, E>>,
+    {
+        let mut partition = Partition::new();
+        partition.extend(iter);
+        partition
+    }
+}
+
+/// Maps which can be filled by [`CollectMap`]
+///
+/// Implemented for `BTreeMap` and, with the `std` feature, for `HashMap`.
+pub trait MapCollection<K, V>: Default {
+    /// Return whether the map already holds a value for `key`
+    fn contains_key(&self, key: &K) -> bool;
+
+    /// Insert `value` under `key`, overwriting any previous value
+    fn insert(&mut self, key: K, value: V);
+}
+
+impl<K, V> MapCollection<K, V> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    #[inline]
+    fn contains_key(&self, key: &K) -> bool {
+        BTreeMap::contains_key(self, key)
+    }
+
+    #[inline]
+    fn insert(&mut self, key: K, value: V) {
+        BTreeMap::insert(self, key, value);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> MapCollection<K, V> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn contains_key(&self, key: &K) -> bool {
+        HashMap::contains_key(self, key)
+    }
+
+    #[inline]
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+}
+
+/// Extension trait for `Iterator<Item = Result<(K, V), E>>` to collect key-value pairs into a map
+pub trait CollectMap<K, V, E>: Sized {
+    /// Collect all `Ok` key-value pairs into a map, stopping at the first `Err`
+    ///
+    /// Later values overwrite earlier ones with the same key.
+    ///
+    /// ```
+    /// use resiter::collect::CollectMap;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map: Result<BTreeMap<_, _>, &str> = vec![Ok(("a", 1)), Ok(("b", 2)), Ok(("a", 3))]
+    ///     .into_iter()
+    ///     .try_collect_map();
+    ///
+    /// assert_eq!(map.unwrap()["a"], 3);
+    ///
+    /// let map: Result<BTreeMap<_, _>, &str> = vec![Ok(("a", 1)), Err("broken"), Ok(("b", 2))]
+    ///     .into_iter()
+    ///     .try_collect_map();
+    ///
+    /// assert_eq!(map, Err("broken"));
+    /// ```
+    fn try_collect_map<M>(self) -> Result<M, E>
+    where
+        M: MapCollection<K, V>;
+
+    /// Collect all `Ok` key-value pairs into a map, stopping at the first `Err` or duplicate key
+    ///
+    /// If a key was already inserted, `on_duplicate` is called with the duplicate key and its
+    /// value and the returned error ends the collection.
+    ///
+    /// ```
+    /// use resiter::collect::CollectMap;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map: Result<BTreeMap<_, _>, String> = vec![Ok(("a", 1)), Ok(("b", 2))]
+    ///     .into_iter()
+    ///     .try_collect_map_unique(|k, _| format!("duplicate key {}", k));
+    ///
+    /// assert_eq!(map.unwrap().len(), 2);
+    ///
+    /// let map: Result<BTreeMap<_, _>, String> = vec![Ok(("a", 1)), Ok(("b", 2)), Ok(("a", 3))]
+    ///     .into_iter()
+    ///     .try_collect_map_unique(|k, _| format!("duplicate key {}", k));
+    ///
+    /// assert_eq!(map, Err("duplicate key a".to_string()));
+    /// ```
+    fn try_collect_map_unique<M, F>(self, on_duplicate: F) -> Result<M, E>
+    where
+        M: MapCollection<K, V>,
+        F: FnMut(K, V) -> E;
+}
+
+impl<I, K, V, E> CollectMap<K, V, E> for I
+where
+    I: Iterator<Item = Result<(K, V), E>> + Sized,
+{
+    fn try_collect_map<M>(self) -> Result<M, E>
+    where
+        M: MapCollection<K, V>,
+    {
+        let mut map = M::default();
+        for res in self {
+            let (k, v) = res?;
+            map.insert(k, v);
+        }
+        Ok(map)
+    }
+
+    fn try_collect_map_unique<M, F>(self, mut on_duplicate: F) -> Result<M, E>
+    where
+        M: MapCollection<K, V>,
+        F: FnMut(K, V) -> E,
+    {
+        let mut map = M::default();
+        for res in self {
+            let (k, v) = res?;
+            if map.contains_key(&k) {
+                return Err(on_duplicate(k, v));
+            }
+            map.insert(k, v);
+        }
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_ok_reset_on_err() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(2), Err(3), Ok(4), Ok(5), Ok(6)];
+        let iter = v.into_iter().windows_ok(2).reset_on_err();
+
+        assert_eq!(iter.size_hint(), (0, Some(6)));
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![Ok(vec![1, 2]), Err(3), Ok(vec![4, 5]), Ok(vec![5, 6])]
+        );
+    }
+
+    #[test]
+    fn test_windows_ok_huge_size() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let windows: Vec<_> = v.into_iter().windows_ok(usize::MAX).collect();
+        assert_eq!(windows, vec![Err(2)]);
+    }
+
+    #[test]
+    fn test_windows_ok_size_hint() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(2), Ok(3), Ok(4)];
+        let mut iter = v.into_iter().windows_ok(3);
+
+        assert_eq!(iter.size_hint(), (2, Some(4)));
+        assert_eq!(iter.next(), Some(Ok(vec![1, 2, 3])));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.count(), 1);
+    }
+
+    #[test]
+    fn test_chunks_ok_huge_size() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(2), Err(3), Ok(4)];
+        let chunks: Vec<_> = v.into_iter().chunks_ok(usize::MAX).collect();
+        assert_eq!(chunks, vec![Err(3), Ok(vec![1, 2, 4])]);
+    }
+
+    #[test]
+    fn test_chunks_ok_hint() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(4), Ok(5)];
+        let mut iter = v.into_iter().chunks_ok(3);
+        assert_eq!(iter.size_hint(), (2, Some(5)));
+
+        assert_eq!(iter.next(), Some(Err(2)));
+        assert_eq!(iter.size_hint(), (2, Some(4)));
+        assert_eq!(iter.next(), Some(Ok(vec![1, 3, 4])));
+        assert_eq!(iter.next(), Some(Ok(vec![5])));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_try_collect_map_unique_input_error_first() {
+        let v: Vec<Result<(usize, usize), usize>> = vec![Ok((1, 1)), Err(2), Ok((1, 3))];
+        let res: Result<BTreeMap<_, _>, _> = v.into_iter().try_collect_map_unique(|k, _| k + 100);
+
+        assert_eq!(res, Err(2));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_collect_map_into_hash_map() {
+        let v: Vec<Result<(usize, usize), usize>> = vec![Ok((1, 1)), Ok((2, 2)), Ok((1, 3))];
+        let res: Result<HashMap<_, _>, _> = v.into_iter().try_collect_map_unique(|k, _| k);
+
+        assert_eq!(res, Err(1));
+    }
+
+    #[test]
+    fn test_sorted_oks_hint() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(3), Err(1), Ok(2), Err(2)];
+        let mut iter = v.into_iter().sorted_oks().errors_last();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+
+        assert_eq!(iter.next(), Some(Ok(2)));
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![Ok(3), Err(1), Err(2)]);
+    }
+}
@@ -0,0 +1,493 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions to attach context to every error of an `Iterator<Item = Result<O, E>>`
+//!
+//! [`AnyhowContext`] is available with the `anyhow` feature and produces `anyhow::Error`s,
+//! [`EyreWrapErr`] is available with the `eyre` feature and produces `eyre::Report`s.
+
+use core::fmt;
+use core::fmt::Display;
+use core::iter::FusedIterator;
+
+#[cfg(feature = "anyhow")]
+use anyhow;
+#[cfg(feature = "eyre")]
+use eyre;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to wrap errors into `anyhow::Error`
+#[cfg(feature = "anyhow")]
+pub trait AnyhowContext<O, E>: Sized {
+    /// Wrap every `Err` item into an `anyhow::Error` with `context` attached
+    ///
+    /// ```
+    /// extern crate anyhow;
+    /// extern crate resiter;
+    ///
+    /// use resiter::context::AnyhowContext;
+    /// use std::str::FromStr;
+    ///
+    /// let parsed: Vec<anyhow::Result<usize>> = ["1", "a"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .err_context("while parsing config")
+    ///     .collect();
+    ///
+    /// assert_eq!(*parsed[0].as_ref().unwrap(), 1);
+    /// let err = parsed[1].as_ref().unwrap_err();
+    /// assert_eq!(err.to_string(), "while parsing config");
+    /// assert_eq!(err.root_cause().to_string(), "invalid digit found in string");
+    /// ```
+    fn err_context<C>(self, context: C) -> ErrContext<Self, C>
+    where
+        C: Display + Clone + Send + Sync + 'static,
+        Result<O, E>: anyhow::Context<O, E>;
+
+    /// Wrap every `Err` item into an `anyhow::Error` with the context returned by `f` attached
+    ///
+    /// `f` is only called for `Err` items.
+    ///
+    /// ```
+    /// extern crate anyhow;
+    /// extern crate resiter;
+    ///
+    /// use resiter::context::AnyhowContext;
+    /// use std::str::FromStr;
+    ///
+    /// let file = "config.toml";
+    /// let parsed: Vec<anyhow::Result<usize>> = ["1", "a"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .with_err_context(|| format!("while parsing {}", file))
+    ///     .collect();
+    ///
+    /// let err = parsed[1].as_ref().unwrap_err();
+    /// assert_eq!(err.to_string(), "while parsing config.toml");
+    /// ```
+    fn with_err_context<F, C>(self, f: F) -> WithErrContext<Self, F>
+    where
+        F: FnMut() -> C,
+        C: Display + Send + Sync + 'static,
+        Result<O, E>: anyhow::Context<O, E>;
+}
+
+#[cfg(feature = "anyhow")]
+impl<I, O, E> AnyhowContext<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn err_context<C>(self, context: C) -> ErrContext<Self, C>
+    where
+        C: Display + Clone + Send + Sync + 'static,
+        Result<O, E>: anyhow::Context<O, E>,
+    {
+        ErrContext {
+            iter: self,
+            context,
+        }
+    }
+
+    #[inline]
+    fn with_err_context<F, C>(self, f: F) -> WithErrContext<Self, F>
+    where
+        F: FnMut() -> C,
+        C: Display + Send + Sync + 'static,
+        Result<O, E>: anyhow::Context<O, E>,
+    {
+        WithErrContext { iter: self, f }
+    }
+}
+
+#[cfg(feature = "anyhow")]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ErrContext<I, C> {
+    iter: I,
+    context: C,
+}
+
+#[cfg(feature = "anyhow")]
+impl<I, O, E, C> Iterator for ErrContext<I, C>
+where
+    I: Iterator<Item = Result<O, E>>,
+    C: Display + Clone + Send + Sync + 'static,
+    Result<O, E>: anyhow::Context<O, E>,
+{
+    type Item = anyhow::Result<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let context = &self.context;
+        self.iter
+            .next()
+            .map(|r| anyhow::Context::with_context(r, || context.clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let context = self.context;
+        self.iter.fold(init, move |acc, r| {
+            g(acc, anyhow::Context::with_context(r, || context.clone()))
+        })
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl<I, O, E, C> DoubleEndedIterator for ErrContext<I, C>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    C: Display + Clone + Send + Sync + 'static,
+    Result<O, E>: anyhow::Context<O, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let context = &self.context;
+        self.iter
+            .next_back()
+            .map(|r| anyhow::Context::with_context(r, || context.clone()))
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl<I, O, E, C> FusedIterator for ErrContext<I, C>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    C: Display + Clone + Send + Sync + 'static,
+    Result<O, E>: anyhow::Context<O, E>,
+{
+}
+
+#[cfg(feature = "anyhow")]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct WithErrContext<I, F> {
+    iter: I,
+    f: F,
+}
+
+#[cfg(feature = "anyhow")]
+impl<I, F> fmt::Debug for WithErrContext<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WithErrContext")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl<I, O, E, F, C> Iterator for WithErrContext<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut() -> C,
+    C: Display + Send + Sync + 'static,
+    Result<O, E>: anyhow::Context<O, E>,
+{
+    type Item = anyhow::Result<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter
+            .next()
+            .map(|r| anyhow::Context::with_context(r, f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| {
+            g(acc, anyhow::Context::with_context(r, &mut f))
+        })
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl<I, O, E, F, C> DoubleEndedIterator for WithErrContext<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut() -> C,
+    C: Display + Send + Sync + 'static,
+    Result<O, E>: anyhow::Context<O, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter
+            .next_back()
+            .map(|r| anyhow::Context::with_context(r, f))
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl<I, O, E, F, C> FusedIterator for WithErrContext<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut() -> C,
+    C: Display + Send + Sync + 'static,
+    Result<O, E>: anyhow::Context<O, E>,
+{
+}
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to wrap errors into `eyre::Report`
+#[cfg(feature = "eyre")]
+pub trait EyreWrapErr<O, E>: Sized {
+    /// Wrap every `Err` item into an `eyre::Report` with `msg` attached
+    ///
+    /// ```
+    /// extern crate eyre;
+    /// extern crate resiter;
+    ///
+    /// use resiter::context::EyreWrapErr;
+    /// use std::str::FromStr;
+    ///
+    /// let parsed: Vec<eyre::Result<usize>> = ["1", "a"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .wrap_err("while parsing config")
+    ///     .collect();
+    ///
+    /// assert_eq!(*parsed[0].as_ref().unwrap(), 1);
+    /// let err = parsed[1].as_ref().unwrap_err();
+    /// assert_eq!(err.to_string(), "while parsing config");
+    /// assert_eq!(err.root_cause().to_string(), "invalid digit found in string");
+    /// ```
+    fn wrap_err<D>(self, msg: D) -> WrapErr<Self, D>
+    where
+        D: Display + Clone + Send + Sync + 'static,
+        Result<O, E>: eyre::WrapErr<O, E>;
+
+    /// Wrap every `Err` item into an `eyre::Report` with the message returned by `f` attached
+    ///
+    /// `f` is only called for `Err` items.
+    ///
+    /// ```
+    /// extern crate eyre;
+    /// extern crate resiter;
+    ///
+    /// use resiter::context::EyreWrapErr;
+    /// use std::str::FromStr;
+    ///
+    /// let mut failures = 0;
+    /// let parsed: Vec<eyre::Result<usize>> = ["1", "a", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .wrap_err_with(|| {
+    ///         failures += 1;
+    ///         format!("failure #{}", failures)
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed[2].as_ref().unwrap_err().to_string(), "failure #2");
+    /// ```
+    fn wrap_err_with<F, D>(self, f: F) -> WrapErrWith<Self, F>
+    where
+        F: FnMut() -> D,
+        D: Display + Send + Sync + 'static,
+        Result<O, E>: eyre::WrapErr<O, E>;
+}
+
+#[cfg(feature = "eyre")]
+impl<I, O, E> EyreWrapErr<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn wrap_err<D>(self, msg: D) -> WrapErr<Self, D>
+    where
+        D: Display + Clone + Send + Sync + 'static,
+        Result<O, E>: eyre::WrapErr<O, E>,
+    {
+        WrapErr { iter: self, msg }
+    }
+
+    #[inline]
+    fn wrap_err_with<F, D>(self, f: F) -> WrapErrWith<Self, F>
+    where
+        F: FnMut() -> D,
+        D: Display + Send + Sync + 'static,
+        Result<O, E>: eyre::WrapErr<O, E>,
+    {
+        WrapErrWith { iter: self, f }
+    }
+}
+
+#[cfg(feature = "eyre")]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct WrapErr<I, D> {
+    iter: I,
+    msg: D,
+}
+
+#[cfg(feature = "eyre")]
+impl<I, O, E, D> Iterator for WrapErr<I, D>
+where
+    I: Iterator<Item = Result<O, E>>,
+    D: Display + Clone + Send + Sync + 'static,
+    Result<O, E>: eyre::WrapErr<O, E>,
+{
+    type Item = eyre::Result<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let msg = &self.msg;
+        self.iter
+            .next()
+            .map(|r| eyre::WrapErr::wrap_err_with(r, || msg.clone()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let msg = self.msg;
+        self.iter.fold(init, move |acc, r| {
+            g(acc, eyre::WrapErr::wrap_err_with(r, || msg.clone()))
+        })
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl<I, O, E, D> DoubleEndedIterator for WrapErr<I, D>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    D: Display + Clone + Send + Sync + 'static,
+    Result<O, E>: eyre::WrapErr<O, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let msg = &self.msg;
+        self.iter
+            .next_back()
+            .map(|r| eyre::WrapErr::wrap_err_with(r, || msg.clone()))
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl<I, O, E, D> FusedIterator for WrapErr<I, D>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    D: Display + Clone + Send + Sync + 'static,
+    Result<O, E>: eyre::WrapErr<O, E>,
+{
+}
+
+#[cfg(feature = "eyre")]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct WrapErrWith<I, F> {
+    iter: I,
+    f: F,
+}
+
+#[cfg(feature = "eyre")]
+impl<I, F> fmt::Debug for WrapErrWith<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WrapErrWith")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl<I, O, E, F, D> Iterator for WrapErrWith<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut() -> D,
+    D: Display + Send + Sync + 'static,
+    Result<O, E>: eyre::WrapErr<O, E>,
+{
+    type Item = eyre::Result<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter.next().map(|r| eyre::WrapErr::wrap_err_with(r, f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| {
+            g(acc, eyre::WrapErr::wrap_err_with(r, &mut f))
+        })
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl<I, O, E, F, D> DoubleEndedIterator for WrapErrWith<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut() -> D,
+    D: Display + Send + Sync + 'static,
+    Result<O, E>: eyre::WrapErr<O, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter
+            .next_back()
+            .map(|r| eyre::WrapErr::wrap_err_with(r, f))
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl<I, O, E, F, D> FusedIterator for WrapErrWith<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut() -> D,
+    D: Display + Send + Sync + 'static,
+    Result<O, E>: eyre::WrapErr<O, E>,
+{
+}
+
+#[cfg(all(test, feature = "anyhow"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_err_context_on_anyhow_errors() {
+        let v: Vec<anyhow::Result<usize>> = vec![Ok(1), Err(anyhow::anyhow!("inner"))];
+        let res: Vec<_> = v.into_iter().err_context("outer").collect();
+
+        let chain: Vec<_> = res[1]
+            .as_ref()
+            .unwrap_err()
+            .chain()
+            .map(|e| e.to_string())
+            .collect();
+        assert_eq!(chain, vec!["outer", "inner"]);
+    }
+}
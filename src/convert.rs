@@ -0,0 +1,432 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// A type-erased, thread-safe error, as produced by [`Convert::boxed_err`]
+#[cfg(feature = "std")]
+pub type BoxedError = Box<dyn Error + Send + Sync>;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to convert Oks and Errors via `From`
+pub trait Convert<O, E>: Sized {
+    /// Convert all `Err` items into `E2` using `From`, while leaving `Ok` as is
+    ///
+    /// ```
+    /// use resiter::convert::Convert;
+    /// use std::str::FromStr;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct MyError(String);
+    ///
+    /// impl From<std::num::ParseIntError> for MyError {
+    ///     fn from(e: std::num::ParseIntError) -> Self {
+    ///         MyError(e.to_string())
+    ///     }
+    /// }
+    ///
+    /// let converted: Vec<Result<usize, MyError>> = ["1", "a"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .err_into()
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     converted,
+    ///     vec![Ok(1), Err(MyError("invalid digit found in string".to_string()))]
+    /// );
+    /// ```
+    fn err_into<E2>(self) -> ErrInto<Self, E2>
+    where
+        E2: From<E>;
+
+    /// Convert all `Ok` items into `O2` using `From`, while leaving `Err` as is
+    ///
+    /// ```
+    /// use resiter::convert::Convert;
+    /// use std::str::FromStr;
+    ///
+    /// let converted: Vec<Result<u64, _>> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| u32::from_str(txt))
+    ///     .ok_into()
+    ///     .collect();
+    ///
+    /// assert_eq!(converted[0], Ok(1u64));
+    /// assert!(converted[1].is_err());
+    /// assert_eq!(converted[2], Ok(3u64));
+    /// ```
+    fn ok_into<O2>(self) -> OkInto<Self, O2>
+    where
+        O2: From<O>;
+
+    /// Box all `Err` items into a [`BoxedError`], while leaving `Ok` as is
+    ///
+    /// This is useful to merge iterators with heterogeneous error types. Only available with the
+    /// `std` feature.
+    ///
+    /// ```
+    /// use resiter::convert::{BoxedError, Convert};
+    /// use std::str::FromStr;
+    ///
+    /// let ints = ["1", "a"].iter().map(|txt| usize::from_str(txt)).boxed_err();
+    /// let floats = ["2.5", "b"].iter().map(|txt| f64::from_str(txt)).boxed_err();
+    ///
+    /// let errors: Vec<BoxedError> = ints
+    ///     .map(|r| r.map(|i| i as f64))
+    ///     .chain(floats)
+    ///     .filter_map(Result::err)
+    ///     .collect();
+    ///
+    /// assert_eq!(errors[0].to_string(), "invalid digit found in string");
+    /// assert_eq!(errors[1].to_string(), "invalid float literal");
+    /// ```
+    #[cfg(feature = "std")]
+    fn boxed_err(self) -> ErrInto<Self, BoxedError>
+    where
+        E: Error + Send + Sync + 'static;
+
+    /// Chain `other` after this iterator, converting its `Err` items into `E` using `Into`
+    ///
+    /// ```
+    /// use resiter::convert::Convert;
+    /// use std::str::FromStr;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct MyError(String);
+    ///
+    /// impl From<std::num::ParseIntError> for MyError {
+    ///     fn from(e: std::num::ParseIntError) -> Self {
+    ///         MyError(e.to_string())
+    ///     }
+    /// }
+    ///
+    /// let chained: Vec<Result<usize, MyError>> = vec![Ok(1), Err(MyError("offline".to_string()))]
+    ///     .into_iter()
+    ///     .chain_err_into(["2", "a"].iter().map(|txt| usize::from_str(txt)))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     chained,
+    ///     vec![
+    ///         Ok(1),
+    ///         Err(MyError("offline".to_string())),
+    ///         Ok(2),
+    ///         Err(MyError("invalid digit found in string".to_string())),
+    ///     ]
+    /// );
+    /// ```
+    fn chain_err_into<U, E2>(self, other: U) -> ChainErrInto<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = Result<O, E2>>,
+        E2: Into<E>;
+}
+
+impl<I, O, E> Convert<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn err_into<E2>(self) -> ErrInto<Self, E2>
+    where
+        E2: From<E>,
+    {
+        ErrInto {
+            iter: self,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn ok_into<O2>(self) -> OkInto<Self, O2>
+    where
+        O2: From<O>,
+    {
+        OkInto {
+            iter: self,
+            _marker: PhantomData,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn boxed_err(self) -> ErrInto<Self, BoxedError>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.err_into()
+    }
+
+    #[inline]
+    fn chain_err_into<U, E2>(self, other: U) -> ChainErrInto<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = Result<O, E2>>,
+        E2: Into<E>,
+    {
+        ChainErrInto {
+            a: Some(self),
+            b: Some(other.into_iter()),
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ErrInto<I, E2> {
+    iter: I,
+    _marker: PhantomData<fn() -> E2>,
+}
+
+impl<I, E2> Clone for ErrInto<I, E2>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        ErrInto {
+            iter: self.iter.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, E2> fmt::Debug for ErrInto<I, E2>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ErrInto").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, O, E, E2> Iterator for ErrInto<I, E2>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E2: From<E>,
+{
+    type Item = Result<O, E2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| r.map_err(E2::from))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter
+            .fold(init, move |acc, r| g(acc, r.map_err(E2::from)))
+    }
+}
+
+impl<I, O, E, E2> DoubleEndedIterator for ErrInto<I, E2>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    E2: From<E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|r| r.map_err(E2::from))
+    }
+}
+
+impl<I, O, E, E2> FusedIterator for ErrInto<I, E2>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    E2: From<E>,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct OkInto<I, O2> {
+    iter: I,
+    _marker: PhantomData<fn() -> O2>,
+}
+
+impl<I, O2> Clone for OkInto<I, O2>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        OkInto {
+            iter: self.iter.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, O2> fmt::Debug for OkInto<I, O2>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OkInto").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, O, E, O2> Iterator for OkInto<I, O2>
+where
+    I: Iterator<Item = Result<O, E>>,
+    O2: From<O>,
+{
+    type Item = Result<O2, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| r.map(O2::from))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, r| g(acc, r.map(O2::from)))
+    }
+}
+
+impl<I, O, E, O2> DoubleEndedIterator for OkInto<I, O2>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    O2: From<O>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|r| r.map(O2::from))
+    }
+}
+
+impl<I, O, E, O2> FusedIterator for OkInto<I, O2>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    O2: From<O>,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ChainErrInto<A, B> {
+    /// `None` once exhausted
+    a: Option<A>,
+    /// `None` once exhausted
+    b: Option<B>,
+}
+
+impl<A, B, O, E, E2> Iterator for ChainErrInto<A, B>
+where
+    A: Iterator<Item = Result<O, E>>,
+    B: Iterator<Item = Result<O, E2>>,
+    E2: Into<E>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(ref mut a) = self.a {
+            match a.next() {
+                None => self.a = None,
+                item => return item,
+            }
+        }
+        if let Some(ref mut b) = self.b {
+            match b.next() {
+                None => self.b = None,
+                item => return item.map(|r| r.map_err(Into::into)),
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.as_ref().map_or((0, Some(0)), Iterator::size_hint);
+        let (b_lo, b_hi) = self.b.as_ref().map_or((0, Some(0)), Iterator::size_hint);
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lo.saturating_add(b_lo), hi)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(a) = self.a {
+            acc = a.fold(acc, &mut g);
+        }
+        if let Some(b) = self.b {
+            acc = b.fold(acc, move |acc, r| g(acc, r.map_err(Into::into)));
+        }
+        acc
+    }
+}
+
+impl<A, B, O, E, E2> DoubleEndedIterator for ChainErrInto<A, B>
+where
+    A: DoubleEndedIterator<Item = Result<O, E>>,
+    B: DoubleEndedIterator<Item = Result<O, E2>>,
+    E2: Into<E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(ref mut b) = self.b {
+            match b.next_back() {
+                None => self.b = None,
+                item => return item.map(|r| r.map_err(Into::into)),
+            }
+        }
+        if let Some(ref mut a) = self.a {
+            match a.next_back() {
+                None => self.a = None,
+                item => return item,
+            }
+        }
+        None
+    }
+}
+
+impl<A, B, O, E, E2> FusedIterator for ChainErrInto<A, B>
+where
+    A: Iterator<Item = Result<O, E>>,
+    B: Iterator<Item = Result<O, E2>>,
+    E2: Into<E>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_err_into_rev() {
+        let v: Vec<Result<u8, u8>> = vec![Ok(1), Err(2), Ok(3)];
+        let res: Vec<Result<u8, u32>> = v.into_iter().err_into().rev().collect();
+
+        assert_eq!(res, vec![Ok(3), Err(2), Ok(1)]);
+    }
+
+    #[test]
+    fn test_chain_err_into_rev() {
+        let a: Vec<Result<u8, u32>> = vec![Ok(1), Err(2)];
+        let b: Vec<Result<u8, u8>> = vec![Err(3), Ok(4)];
+        let res: Vec<_> = a.into_iter().chain_err_into(b).rev().collect();
+
+        assert_eq!(res, vec![Ok(4), Err(3), Err(2), Ok(1)]);
+    }
+}
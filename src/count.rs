@@ -0,0 +1,369 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+
+/// Number of `Ok` and `Err` items, as returned by [`CountResults::count_results`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ResultCounts {
+    /// Number of `Ok` items
+    pub oks: usize,
+    /// Number of `Err` items
+    pub errs: usize,
+}
+
+impl ResultCounts {
+    /// Total number of items
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.oks + self.errs
+    }
+}
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to count `Ok` and `Err` items
+pub trait CountResults<O, E>: Sized {
+    /// Drain the iterator and count all `Ok` and all `Err` items in one pass
+    ///
+    /// ```
+    /// use resiter::count::{CountResults, ResultCounts};
+    /// use std::str::FromStr;
+    ///
+    /// let counts = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .count_results();
+    ///
+    /// assert_eq!(counts, ResultCounts { oks: 3, errs: 2 });
+    /// assert_eq!(counts.total(), 5);
+    /// ```
+    fn count_results(self) -> ResultCounts;
+
+    /// Drain the iterator into a [`ResultSummary`], keeping only the first and the last error
+    ///
+    /// ```
+    /// use resiter::count::CountResults;
+    /// use std::str::FromStr;
+    ///
+    /// let summary = ["1", "a", "3", "-4"]
+    ///     .iter()
+    ///     .map(|txt| u8::from_str(txt).map_err(|_| *txt))
+    ///     .summarize();
+    ///
+    /// assert_eq!(summary.ok_count(), 2);
+    /// assert_eq!(summary.err_count(), 2);
+    /// assert_eq!(summary.first_err(), Some(&"a"));
+    /// assert_eq!(summary.last_err(), Some(&"-4"));
+    /// assert!(!summary.is_all_ok());
+    /// ```
+    fn summarize(self) -> ResultSummary<E>;
+
+    /// Call `f` with the running counts after every `n`th item, passing all items through
+    ///
+    /// This is meant for cheap progress reporting on long-running iterators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use resiter::count::{CountResults, ResultCounts};
+    /// use std::str::FromStr;
+    ///
+    /// let mut progress = Vec::new();
+    /// let res: Vec<_> = ["1", "a", "3", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .on_every_nth(2, |counts| progress.push(counts))
+    ///     .collect();
+    ///
+    /// assert_eq!(res.len(), 5);
+    /// assert_eq!(
+    ///     progress,
+    ///     vec![ResultCounts { oks: 1, errs: 1 }, ResultCounts { oks: 3, errs: 1 }]
+    /// );
+    /// ```
+    fn on_every_nth<F>(self, n: usize, f: F) -> OnEveryNth<Self, F>
+    where
+        F: FnMut(ResultCounts);
+}
+
+impl<I, O, E> CountResults<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn count_results(self) -> ResultCounts {
+        self.fold(ResultCounts::default(), |mut counts, res| {
+            count_into(&mut counts, &res);
+            counts
+        })
+    }
+
+    #[inline]
+    fn summarize(self) -> ResultSummary<E> {
+        self.collect()
+    }
+
+    #[inline]
+    fn on_every_nth<F>(self, n: usize, f: F) -> OnEveryNth<Self, F>
+    where
+        F: FnMut(ResultCounts),
+    {
+        assert!(n != 0, "n must be non-zero");
+        OnEveryNth {
+            iter: self,
+            n,
+            remaining: n,
+            counts: ResultCounts::default(),
+            f,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct OnEveryNth<I, F> {
+    iter: I,
+    n: usize,
+    /// Number of items until `f` is called next
+    remaining: usize,
+    counts: ResultCounts,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for OnEveryNth<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OnEveryNth")
+            .field("iter", &self.iter)
+            .field("n", &self.n)
+            .field("remaining", &self.remaining)
+            .field("counts", &self.counts)
+            .finish()
+    }
+}
+
+impl<I, F> OnEveryNth<I, F>
+where
+    F: FnMut(ResultCounts),
+{
+    #[inline]
+    fn record<O, E>(&mut self, r: &Result<O, E>) {
+        count_into(&mut self.counts, r);
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            self.remaining = self.n;
+            (self.f)(self.counts);
+        }
+    }
+}
+
+#[inline]
+fn count_into<O, E>(counts: &mut ResultCounts, r: &Result<O, E>) {
+    match *r {
+        Ok(_) => counts.oks += 1,
+        Err(_) => counts.errs += 1,
+    }
+}
+
+impl<I, O, E, F> Iterator for OnEveryNth<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(ResultCounts),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        self.record(&r);
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let n = self.n;
+        let mut remaining = self.remaining;
+        let mut counts = self.counts;
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| {
+            count_into(&mut counts, &r);
+            remaining -= 1;
+            if remaining == 0 {
+                remaining = n;
+                f(counts);
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for OnEveryNth<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(ResultCounts),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        self.record(&r);
+        Some(r)
+    }
+}
+
+impl<I, O, E, F> FusedIterator for OnEveryNth<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(ResultCounts),
+{
+}
+
+/// Aggregated statistics of an `Iterator<Item = Result<O, E>>`
+///
+/// Besides the number of `Ok` and `Err` items, only the first and the last error are kept, so
+/// no allocation is needed. Can be built with [`CountResults::summarize`] or by collecting.
+///
+/// ```
+/// use resiter::count::ResultSummary;
+///
+/// let summary: ResultSummary<&str> = vec![Ok(1), Ok(2)].into_iter().collect();
+///
+/// assert!(summary.is_all_ok());
+/// assert_eq!(summary.ok_count(), 2);
+/// assert_eq!(summary.first_err(), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ResultSummary<E> {
+    counts: ResultCounts,
+    first_err: Option<E>,
+    /// Only set from the second error on
+    last_err: Option<E>,
+}
+
+impl<E> ResultSummary<E> {
+    /// Create an empty summary
+    #[inline]
+    pub fn new() -> Self {
+        ResultSummary {
+            counts: ResultCounts::default(),
+            first_err: None,
+            last_err: None,
+        }
+    }
+
+    /// Number of `Ok` items
+    #[inline]
+    pub fn ok_count(&self) -> usize {
+        self.counts.oks
+    }
+
+    /// Number of `Err` items
+    #[inline]
+    pub fn err_count(&self) -> usize {
+        self.counts.errs
+    }
+
+    /// Number of `Ok` and `Err` items
+    #[inline]
+    pub fn counts(&self) -> ResultCounts {
+        self.counts
+    }
+
+    /// The first `Err` item, if any
+    #[inline]
+    pub fn first_err(&self) -> Option<&E> {
+        self.first_err.as_ref()
+    }
+
+    /// The last `Err` item, if any
+    ///
+    /// This is the same as [`ResultSummary::first_err`] if there was only one error.
+    #[inline]
+    pub fn last_err(&self) -> Option<&E> {
+        self.last_err.as_ref().or(self.first_err.as_ref())
+    }
+
+    /// Whether there were no `Err` items
+    #[inline]
+    pub fn is_all_ok(&self) -> bool {
+        self.counts.errs == 0
+    }
+}
+
+impl<E> Default for ResultSummary<E> {
+    #[inline]
+    fn default() -> Self {
+        ResultSummary::new()
+    }
+}
+
+impl<O, E> Extend<Result<O, E>> for ResultSummary<E> {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = Result<O, E>>,
+    {
+        for r in iter {
+            match r {
+                Ok(_) => self.counts.oks += 1,
+                Err(e) => {
+                    self.counts.errs += 1;
+                    if self.first_err.is_none() {
+                        self.first_err = Some(e);
+                    } else {
+                        self.last_err = Some(e);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<O, E> FromIterator<Result<O, E>> for ResultSummary<E> {
+    #[inline]
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = Result<O, E>>,
+    {
+        let mut summary = ResultSummary::new();
+        summary.extend(iter);
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_single_error_is_first_and_last() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let summary = v.into_iter().summarize();
+
+        assert_eq!(summary.counts(), ResultCounts { oks: 2, errs: 1 });
+        assert_eq!(summary.first_err(), Some(&2));
+        assert_eq!(summary.last_err(), Some(&2));
+    }
+
+    #[test]
+    fn test_on_every_nth_fold_after_next() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(4)];
+        let mut calls = Vec::new();
+        let mut iter = v.into_iter().on_every_nth(3, |counts| calls.push(counts));
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.count(), 3);
+        assert_eq!(calls, vec![ResultCounts { oks: 2, errs: 1 }]);
+    }
+}
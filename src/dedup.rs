@@ -0,0 +1,471 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to remove consecutive duplicates
+pub trait Dedup<O, E>: Sized {
+    /// Remove consecutive equal `Ok` values, passing all `Err` items through
+    ///
+    /// Errors do not interrupt a run of equal values: an `Ok` item is compared against the last
+    /// yielded `Ok` value, no matter how many errors were yielded in between. The last value is
+    /// cloned for this comparison, so no item is ever held back and the order of `Ok` and `Err`
+    /// items is preserved.
+    ///
+    /// ```
+    /// use resiter::dedup::Dedup;
+    /// use std::str::FromStr;
+    ///
+    /// let deduped: Vec<_> = ["1", "1", "a", "1", "2", "2", "1"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .dedup_ok()
+    ///     .collect();
+    ///
+    /// assert_eq!(deduped.len(), 4);
+    /// assert_eq!(deduped[0], Ok(1));
+    /// assert!(deduped[1].is_err());
+    /// assert_eq!(deduped[2], Ok(2));
+    /// assert_eq!(deduped[3], Ok(1));
+    /// ```
+    fn dedup_ok(self) -> DedupOk<Self, O>
+    where
+        O: PartialEq + Clone;
+
+    /// Remove consecutive `Ok` values which map to equal keys, passing all `Err` items through
+    ///
+    /// Like [`Dedup::dedup_ok`], errors do not interrupt a run of equal keys. Only the key of the
+    /// last yielded `Ok` value is kept, so `O` does not need to implement `Clone`.
+    ///
+    /// ```
+    /// use resiter::dedup::Dedup;
+    ///
+    /// let deduped: Vec<Result<(u32, &str), &str>> = vec![
+    ///     Ok((10, "start")),
+    ///     Ok((10, "still starting")),
+    ///     Err("timeout"),
+    ///     Ok((20, "running")),
+    /// ]
+    /// .into_iter()
+    /// .dedup_ok_by_key(|record| record.0)
+    /// .collect();
+    ///
+    /// assert_eq!(
+    ///     deduped,
+    ///     vec![Ok((10, "start")), Err("timeout"), Ok((20, "running"))]
+    /// );
+    /// ```
+    fn dedup_ok_by_key<F, K>(self, _: F) -> DedupOkByKey<Self, F, K>
+    where
+        F: FnMut(&O) -> K,
+        K: PartialEq;
+
+    /// Collapse runs of consecutive equal `Err` items into one, passing all `Ok` items through
+    ///
+    /// An `Ok` item ends a run. The first error of a run is only yielded once the run ended, so
+    /// one item is held back while it is being compared.
+    ///
+    /// ```
+    /// use resiter::dedup::Dedup;
+    ///
+    /// let deduped: Vec<Result<usize, &str>> = vec![
+    ///     Err("offline"),
+    ///     Err("offline"),
+    ///     Err("offline"),
+    ///     Err("timeout"),
+    ///     Ok(1),
+    ///     Err("timeout"),
+    /// ]
+    /// .into_iter()
+    /// .dedup_errs()
+    /// .collect();
+    ///
+    /// assert_eq!(
+    ///     deduped,
+    ///     vec![Err("offline"), Err("timeout"), Ok(1), Err("timeout")]
+    /// );
+    /// ```
+    fn dedup_errs(self) -> DedupErrs<Self, O, E>
+    where
+        E: PartialEq;
+
+    /// Collapse runs of consecutive equal `Err` items into one, annotated with the length of the
+    /// run
+    ///
+    /// See [`Dedup::dedup_errs`].
+    ///
+    /// ```
+    /// use resiter::dedup::Dedup;
+    ///
+    /// let deduped: Vec<Result<usize, (&str, usize)>> =
+    ///     vec![Err("offline"), Err("offline"), Err("offline"), Ok(1), Err("timeout")]
+    ///         .into_iter()
+    ///         .dedup_errs_counted()
+    ///         .collect();
+    ///
+    /// assert_eq!(
+    ///     deduped,
+    ///     vec![Err(("offline", 3)), Ok(1), Err(("timeout", 1))]
+    /// );
+    /// ```
+    fn dedup_errs_counted(self) -> DedupErrsCounted<Self, O, E>
+    where
+        E: PartialEq;
+}
+
+impl<I, O, E> Dedup<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn dedup_ok(self) -> DedupOk<Self, O>
+    where
+        O: PartialEq + Clone,
+    {
+        DedupOk {
+            iter: self,
+            last: None,
+        }
+    }
+
+    #[inline]
+    fn dedup_ok_by_key<F, K>(self, f: F) -> DedupOkByKey<Self, F, K>
+    where
+        F: FnMut(&O) -> K,
+        K: PartialEq,
+    {
+        DedupOkByKey {
+            iter: self,
+            f,
+            last: None,
+        }
+    }
+
+    #[inline]
+    fn dedup_errs(self) -> DedupErrs<Self, O, E>
+    where
+        E: PartialEq,
+    {
+        DedupErrs {
+            inner: self.dedup_errs_counted(),
+        }
+    }
+
+    #[inline]
+    fn dedup_errs_counted(self) -> DedupErrsCounted<Self, O, E>
+    where
+        E: PartialEq,
+    {
+        DedupErrsCounted {
+            iter: self,
+            peeked: None,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct DedupOk<I, O> {
+    iter: I,
+    last: Option<O>,
+}
+
+impl<I, O> fmt::Debug for DedupOk<I, O>
+where
+    I: fmt::Debug,
+    O: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DedupOk")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+impl<I, O, E> Iterator for DedupOk<I, O>
+where
+    I: Iterator<Item = Result<O, E>>,
+    O: PartialEq + Clone,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(o) => {
+                    if self.last.as_ref() != Some(&o) {
+                        self.last = Some(o.clone());
+                        return Some(Ok(o));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        if self.last.is_none() {
+            (lo.min(1), hi)
+        } else {
+            (0, hi)
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut last = self.last;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(o) => {
+                if last.as_ref() != Some(&o) {
+                    last = Some(o.clone());
+                    g(acc, Ok(o))
+                } else {
+                    acc
+                }
+            }
+            Err(e) => g(acc, Err(e)),
+        })
+    }
+}
+
+impl<I, O, E> FusedIterator for DedupOk<I, O>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    O: PartialEq + Clone,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct DedupOkByKey<I, F, K> {
+    iter: I,
+    f: F,
+    last: Option<K>,
+}
+
+impl<I, F, K> fmt::Debug for DedupOkByKey<I, F, K>
+where
+    I: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DedupOkByKey")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, K> Iterator for DedupOkByKey<I, F, K>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&O) -> K,
+    K: PartialEq,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(o) => {
+                    let key = (self.f)(&o);
+                    if self.last.as_ref() != Some(&key) {
+                        self.last = Some(key);
+                        return Some(Ok(o));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        if self.last.is_none() {
+            (lo.min(1), hi)
+        } else {
+            (0, hi)
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        let mut last = self.last;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(o) => {
+                let key = f(&o);
+                if last.as_ref() != Some(&key) {
+                    last = Some(key);
+                    g(acc, Ok(o))
+                } else {
+                    acc
+                }
+            }
+            Err(e) => g(acc, Err(e)),
+        })
+    }
+}
+
+impl<I, O, E, F, K> FusedIterator for DedupOkByKey<I, F, K>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&O) -> K,
+    K: PartialEq,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct DedupErrsCounted<I, O, E> {
+    iter: I,
+    /// The item which ended the last run of errors
+    peeked: Option<Result<O, E>>,
+}
+
+impl<I, O, E> fmt::Debug for DedupErrsCounted<I, O, E>
+where
+    I: fmt::Debug,
+    O: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DedupErrsCounted")
+            .field("iter", &self.iter)
+            .field("peeked", &self.peeked)
+            .finish()
+    }
+}
+
+impl<I, O, E> Iterator for DedupErrsCounted<I, O, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: PartialEq,
+{
+    type Item = Result<O, (E, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.peeked.take() {
+            Some(r) => r,
+            None => self.iter.next()?,
+        };
+        let e = match first {
+            Ok(o) => return Some(Ok(o)),
+            Err(e) => e,
+        };
+
+        let mut count = 1;
+        loop {
+            match self.iter.next() {
+                Some(Err(ref next)) if *next == e => count += 1,
+                other => {
+                    self.peeked = other;
+                    return Some(Err((e, count)));
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peek_len = self.peeked.is_some() as usize;
+        let (lo, hi) = self.iter.size_hint();
+        (
+            lo.saturating_add(peek_len).min(1),
+            hi.and_then(|hi| hi.checked_add(peek_len)),
+        )
+    }
+}
+
+impl<I, O, E> FusedIterator for DedupErrsCounted<I, O, E>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    E: PartialEq,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct DedupErrs<I, O, E> {
+    inner: DedupErrsCounted<I, O, E>,
+}
+
+impl<I, O, E> Iterator for DedupErrs<I, O, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: PartialEq,
+{
+    type Item = Result<O, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|r| r.map_err(|(e, _)| e))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, O, E> FusedIterator for DedupErrs<I, O, E>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    E: PartialEq,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup_ok_fold() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(1), Err(1), Err(1), Ok(1), Ok(2)];
+        let res = v.into_iter().dedup_ok().fold(Vec::new(), |mut acc, r| {
+            acc.push(r);
+            acc
+        });
+
+        assert_eq!(res, vec![Ok(1), Err(1), Err(1), Ok(2)]);
+    }
+
+    #[test]
+    fn test_dedup_ok_by_key_hint() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(11), Ok(2)];
+        let mut iter = v.into_iter().dedup_ok_by_key(|i| i % 10);
+        assert_eq!(iter.size_hint(), (1, Some(3)));
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.size_hint(), (0, Some(2)));
+        assert_eq!(iter.next(), Some(Ok(2)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_dedup_errs_counted_hint() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Err(1), Ok(2), Err(1)];
+        let mut iter = v.into_iter().dedup_errs_counted();
+        assert_eq!(iter.size_hint(), (1, Some(4)));
+
+        assert_eq!(iter.next(), Some(Err((1, 2))));
+        assert_eq!(iter.size_hint(), (1, Some(2)));
+        assert_eq!(iter.next(), Some(Ok(2)));
+        assert_eq!(iter.next(), Some(Err((1, 1))));
+        assert_eq!(iter.next(), None);
+    }
+}
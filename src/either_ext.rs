@@ -0,0 +1,213 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions to move between `Iterator<Item = Result<O, E>>` and iterators over `Either`
+//!
+//! Conversions follow the convention of the `either` crate: `Ok` corresponds to
+//! `Either::Right` and `Err` to `Either::Left`.
+//!
+//! Only available with the `either` feature.
+
+use core::iter::FusedIterator;
+use either::Either;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to split or convert items via `Either`
+pub trait EitherResults<O, E>: Sized {
+    /// Split all `Ok` values into two containers by the side `f` puts them on, stopping at the
+    /// first `Err`
+    ///
+    /// ```
+    /// extern crate either;
+    /// extern crate resiter;
+    ///
+    /// use either::Either;
+    /// use resiter::either_ext::EitherResults;
+    /// use std::str::FromStr;
+    ///
+    /// let res: Result<(Vec<usize>, Vec<usize>), _> = ["1", "2", "3", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .partition_map_ok(|i| if i % 2 == 0 { Either::Left(i) } else { Either::Right(i) });
+    ///
+    /// assert_eq!(res, Ok((vec![2, 4], vec![1, 3])));
+    ///
+    /// let res: Result<(Vec<usize>, Vec<usize>), _> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .partition_map_ok(Either::<usize, usize>::Left);
+    ///
+    /// assert!(res.is_err());
+    /// ```
+    fn partition_map_ok<A, B, F, L, R>(self, _: F) -> Result<(A, B), E>
+    where
+        F: FnMut(O) -> Either<L, R>,
+        A: Default + Extend<L>,
+        B: Default + Extend<R>;
+
+    /// Convert every item into an `Either`, with `Err` on the left and `Ok` on the right
+    ///
+    /// ```
+    /// extern crate either;
+    /// extern crate resiter;
+    ///
+    /// use either::Either;
+    /// use resiter::either_ext::EitherResults;
+    ///
+    /// let items: Vec<Either<&str, usize>> = vec![Ok(1), Err("a")]
+    ///     .into_iter()
+    ///     .into_either()
+    ///     .collect();
+    ///
+    /// assert_eq!(items, vec![Either::Right(1), Either::Left("a")]);
+    /// ```
+    fn into_either(self) -> IntoEither<Self>;
+}
+
+impl<I, O, E> EitherResults<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    fn partition_map_ok<A, B, F, L, R>(self, mut f: F) -> Result<(A, B), E>
+    where
+        F: FnMut(O) -> Either<L, R>,
+        A: Default + Extend<L>,
+        B: Default + Extend<R>,
+    {
+        let mut left = A::default();
+        let mut right = B::default();
+        for res in self {
+            match f(res?) {
+                Either::Left(l) => left.extend(Some(l)),
+                Either::Right(r) => right.extend(Some(r)),
+            }
+        }
+        Ok((left, right))
+    }
+
+    #[inline]
+    fn into_either(self) -> IntoEither<Self> {
+        IntoEither { iter: self }
+    }
+}
+
+/// Extension trait for `Iterator<Item = Either<L, R>>` to convert it into an iterator over
+/// `Result`s
+pub trait EitherIntoResults<L, R>: Sized {
+    /// Convert every item into a `Result`, with `Either::Right` becoming `Ok` and `Either::Left`
+    /// becoming `Err`
+    ///
+    /// ```
+    /// extern crate either;
+    /// extern crate resiter;
+    ///
+    /// use either::Either;
+    /// use resiter::either_ext::EitherIntoResults;
+    ///
+    /// let items: Vec<Result<usize, &str>> = vec![Either::Right(1), Either::Left("a")]
+    ///     .into_iter()
+    ///     .into_results()
+    ///     .collect();
+    ///
+    /// assert_eq!(items, vec![Ok(1), Err("a")]);
+    /// ```
+    fn into_results(self) -> IntoResults<Self>;
+}
+
+impl<I, L, R> EitherIntoResults<L, R> for I
+where
+    I: Iterator<Item = Either<L, R>> + Sized,
+{
+    #[inline]
+    fn into_results(self) -> IntoResults<Self> {
+        IntoResults { iter: self }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct IntoEither<I> {
+    iter: I,
+}
+
+impl<I, O, E> Iterator for IntoEither<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Either<E, O>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Either::from)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, r| g(acc, Either::from(r)))
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for IntoEither<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Either::from)
+    }
+}
+
+impl<I, O, E> FusedIterator for IntoEither<I> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct IntoResults<I> {
+    iter: I,
+}
+
+impl<I, L, R> Iterator for IntoResults<I>
+where
+    I: Iterator<Item = Either<L, R>>,
+{
+    type Item = Result<R, L>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Either::into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, e| g(acc, e.into()))
+    }
+}
+
+impl<I, L, R> DoubleEndedIterator for IntoResults<I>
+where
+    I: DoubleEndedIterator<Item = Either<L, R>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Either::into)
+    }
+}
+
+impl<I, L, R> FusedIterator for IntoResults<I> where I: FusedIterator<Item = Either<L, R>> {}
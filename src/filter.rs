@@ -4,6 +4,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to filter one kind of result (and leaving the other as is)
 pub trait Filter<O, E>: Sized {
     /// Filter `Ok` items while keeping `Err`
@@ -43,6 +46,52 @@ pub trait Filter<O, E>: Sized {
     fn filter_err<F>(self, _: F) -> FilterErr<Self, F>
     where
         F: FnMut(&E) -> bool;
+
+    /// Filter `Err` values with a function which can fail, while keeping `Ok`
+    ///
+    /// If `f` fails, its error is converted via `Into` and yielded in place of the filtered
+    /// error.
+    ///
+    /// This is [`TryFilter::try_filter_err`](crate::try_filter::TryFilter::try_filter_err) with
+    /// the additional `Into` conversion. If `f` already fails with `E`, prefer `try_filter_err`,
+    /// as its error type needs no annotation then.
+    ///
+    /// ```
+    /// use resiter::filter::Filter;
+    /// use std::str::FromStr;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Parse(String),
+    ///     Allowlist,
+    /// }
+    ///
+    /// impl From<()> for Error {
+    ///     fn from(_: ()) -> Self {
+    ///         Error::Allowlist
+    ///     }
+    /// }
+    ///
+    /// let mut allowlist_available = true;
+    /// let filtered: Vec<_> = ["1", "a", "b", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|_| Error::Parse(txt.to_string())))
+    ///     .filter_err_and_then(|_| {
+    ///         if allowlist_available {
+    ///             allowlist_available = false;
+    ///             Ok(false)
+    ///         } else {
+    ///             Err(())
+    ///         }
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(filtered, vec![Ok(1), Err(Error::Allowlist), Ok(4)]);
+    /// ```
+    fn filter_err_and_then<F, E2>(self, _: F) -> FilterErrAndThen<Self, F>
+    where
+        F: FnMut(&E) -> Result<bool, E2>,
+        E2: Into<E>;
 }
 
 impl<I, O, E> Filter<O, E> for I
@@ -64,14 +113,35 @@ where
     {
         FilterErr { iter: self, f }
     }
+
+    #[inline]
+    fn filter_err_and_then<F, E2>(self, f: F) -> FilterErrAndThen<Self, F>
+    where
+        F: FnMut(&E) -> Result<bool, E2>,
+        E2: Into<E>,
+    {
+        FilterErrAndThen { iter: self, f }
+    }
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct FilterOk<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for FilterOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, F> Iterator for FilterOk<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -99,14 +169,72 @@ where
         let hint_sup = self.iter.size_hint().1;
         (0, hint_sup)
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(x) => {
+                if f(&x) {
+                    g(acc, Ok(x))
+                } else {
+                    acc
+                }
+            }
+            Err(e) => g(acc, Err(e)),
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for FilterOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&O) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back() {
+                Some(Ok(x)) => {
+                    if (self.f)(&x) {
+                        return Some(Ok(x));
+                    }
+                }
+                other => {
+                    return other;
+                }
+            }
+        }
+    }
+}
+
+impl<I, O, E, F> FusedIterator for FilterOk<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&O) -> bool,
+{
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct FilterErr<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for FilterErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterErr")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, F> Iterator for FilterErr<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -134,6 +262,142 @@ where
         let hint_sup = self.iter.size_hint().1;
         (0, hint_sup)
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Err(x) => {
+                if f(&x) {
+                    g(acc, Err(x))
+                } else {
+                    acc
+                }
+            }
+            Ok(o) => g(acc, Ok(o)),
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for FilterErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back() {
+                Some(Err(x)) => {
+                    if (self.f)(&x) {
+                        return Some(Err(x));
+                    }
+                }
+                other => {
+                    return other;
+                }
+            }
+        }
+    }
+}
+
+impl<I, O, E, F> FusedIterator for FilterErr<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> bool,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct FilterErrAndThen<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for FilterErrAndThen<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterErrAndThen")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, E2> Iterator for FilterErrAndThen<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Result<bool, E2>,
+    E2: Into<E>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            return match self.iter.next() {
+                Some(Err(x)) => match (self.f)(&x) {
+                    Ok(true) => Some(Err(x)),
+                    Ok(false) => continue,
+                    Err(e) => Some(Err(e.into())),
+                },
+                other => other,
+            };
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Err(x) => match f(&x) {
+                Ok(true) => g(acc, Err(x)),
+                Ok(false) => acc,
+                Err(e) => g(acc, Err(e.into())),
+            },
+            Ok(o) => g(acc, Ok(o)),
+        })
+    }
+}
+
+impl<I, O, E, F, E2> DoubleEndedIterator for FilterErrAndThen<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Result<bool, E2>,
+    E2: Into<E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            return match self.iter.next_back() {
+                Some(Err(x)) => match (self.f)(&x) {
+                    Ok(true) => Some(Err(x)),
+                    Ok(false) => continue,
+                    Err(e) => Some(Err(e.into())),
+                },
+                other => other,
+            };
+        }
+    }
+}
+
+impl<I, O, E, F, E2> FusedIterator for FilterErrAndThen<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Result<bool, E2>,
+    E2: Into<E>,
+{
 }
 
 #[cfg(test)]
@@ -165,4 +429,31 @@ mod tests {
 
         assert_eq!(hint, (0, Some(5)));
     }
+
+    #[test]
+    fn test_filter_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(4)];
+        let filtered: Vec<_> = v.into_iter().filter_ok(|i| i % 2 == 0).rev().collect();
+
+        assert_eq!(filtered, vec![Ok(4), Err(2)]);
+    }
+
+    #[test]
+    fn test_filter_err_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3), Ok(4)];
+        let filtered: Vec<_> = v.into_iter().filter_err(|i| i % 2 == 0).rev().collect();
+
+        assert_eq!(filtered, vec![Ok(4), Err(2), Ok(1)]);
+    }
+
+    #[test]
+    fn test_filter_ok_fold() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(4)];
+        let count = v
+            .into_iter()
+            .filter_ok(|i| i % 2 == 0)
+            .fold(0, |acc, _| acc + 1);
+
+        assert_eq!(count, 2);
+    }
 }
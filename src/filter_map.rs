@@ -4,6 +4,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform and map Oks and Errors.
 pub trait FilterMap<O, E>: Sized {
     fn filter_map_ok<F, O2>(self, _: F) -> FilterMapOk<Self, F>
@@ -85,11 +88,23 @@ where
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct FilterMapOk<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for FilterMapOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterMapOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, F, O2> Iterator for FilterMapOk<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -113,16 +128,71 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(x) => match f(x) {
+                Some(x) => g(acc, Ok(x)),
+                None => acc,
+            },
+            Err(e) => g(acc, Err(e)),
+        })
+    }
+}
+
+impl<I, O, E, F, O2> DoubleEndedIterator for FilterMapOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Option<O2>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back() {
+                Some(Ok(x)) => {
+                    if let Some(x) = (self.f)(x) {
+                        return Some(Ok(x));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            }
+        }
     }
 }
 
+impl<I, O, E, F, O2> FusedIterator for FilterMapOk<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Option<O2>,
+{
+}
+
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct FilterMapErr<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for FilterMapErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterMapErr")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, F, E2> Iterator for FilterMapErr<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -145,10 +215,53 @@ where
     }
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(x) => g(acc, Ok(x)),
+            Err(e) => match f(e) {
+                Some(e) => g(acc, Err(e)),
+                None => acc,
+            },
+        })
     }
 }
 
+impl<I, O, E, F, E2> DoubleEndedIterator for FilterMapErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Option<E2>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back() {
+                Some(Ok(x)) => return Some(Ok(x)),
+                Some(Err(e)) => {
+                    if let Some(e) = (self.f)(e) {
+                        return Some(Err(e));
+                    }
+                }
+                None => return None,
+            }
+        }
+    }
+}
+
+impl<I, O, E, F, E2> FusedIterator for FilterMapErr<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Option<E2>,
+{
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,7 +276,7 @@ mod tests {
             .filter_map_ok(|i| Some(2 * i))
             .size_hint();
 
-        assert_eq!(hint, (5, Some(5)));
+        assert_eq!(hint, (0, Some(5)));
     }
 
     #[test]
@@ -176,6 +289,30 @@ mod tests {
             .filter_map_err(|e| Some(format!("{:?}", e)))
             .size_hint();
 
-        assert_eq!(hint, (5, Some(5)));
+        assert_eq!(hint, (0, Some(5)));
+    }
+
+    #[test]
+    fn test_filter_map_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(4)];
+        let filtered: Vec<_> = v
+            .into_iter()
+            .filter_map_ok(|i| if i % 2 == 0 { Some(i * 10) } else { None })
+            .rev()
+            .collect();
+
+        assert_eq!(filtered, vec![Ok(40), Err(2)]);
+    }
+
+    #[test]
+    fn test_filter_map_err_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3), Ok(4)];
+        let filtered: Vec<_> = v
+            .into_iter()
+            .filter_map_err(|i| if i % 2 == 0 { Some(i * 10) } else { None })
+            .rev()
+            .collect();
+
+        assert_eq!(filtered, vec![Ok(4), Err(20), Ok(1)]);
     }
 }
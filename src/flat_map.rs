@@ -4,6 +4,11 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
+use util::{and_then_or_clear, flatten_size_hint};
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform Oks and Errors.
 pub trait FlatMap<O, E>: Sized {
     /// [flat_map](Iterator::flat_map) every `Ok` value and leave all `Err` as is
@@ -41,6 +46,50 @@ pub trait FlatMap<O, E>: Sized {
     where
         F: FnMut(E) -> U,
         U: IntoIterator<Item = E2>;
+
+    /// [flat_map](Iterator::flat_map) every `Ok` value with a function which can fail
+    ///
+    /// If `f` returns `Err`, that error is yielded in place of the expanded items. `Err` values
+    /// of the iterator are left as is.
+    ///
+    /// ```
+    /// use resiter::flat_map::FlatMap;
+    ///
+    /// let mapped: Vec<_> = vec![Ok(2), Ok(12), Err("input"), Ok(1)]
+    ///     .into_iter()
+    ///     .try_flat_map_ok(|i| if i < 10 { Ok(0..i) } else { Err("too large") })
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     mapped,
+    ///     [Ok(0), Ok(1), Err("too large"), Err("input"), Ok(0)]
+    /// );
+    /// ```
+    fn try_flat_map_ok<U, F, O2>(self, _: F) -> TryFlatMapOk<Self, U, F>
+    where
+        F: FnMut(O) -> Result<U, E>,
+        U: IntoIterator<Item = O2>;
+
+    /// Replace every `Err` value with the zero or more recovery values returned by `f`, yielding
+    /// only `O`
+    ///
+    /// This generalizes [`UnwrapWithExt::unwrap_with`](::unwrap::UnwrapWithExt::unwrap_with) to
+    /// more than one recovery value per error.
+    ///
+    /// ```
+    /// use resiter::flat_map::FlatMap;
+    ///
+    /// let recovered: Vec<_> = vec![Ok(1), Err(2), Ok(5), Err(0)]
+    ///     .into_iter()
+    ///     .recover_with(|missing| vec![0; missing])
+    ///     .collect();
+    ///
+    /// assert_eq!(recovered, [1, 0, 0, 5]);
+    /// ```
+    fn recover_with<U, F>(self, _: F) -> RecoverWith<Self, U, F>
+    where
+        F: FnMut(E) -> U,
+        U: IntoIterator<Item = O>;
 }
 
 impl<I, O, E> FlatMap<O, E> for I
@@ -55,6 +104,7 @@ where
     {
         FlatMapOk {
             frontiter: None,
+            backiter: None,
             iter: self,
             f,
         }
@@ -68,6 +118,35 @@ where
     {
         FlatMapErr {
             frontiter: None,
+            backiter: None,
+            iter: self,
+            f,
+        }
+    }
+
+    #[inline]
+    fn try_flat_map_ok<U, F, O2>(self, f: F) -> TryFlatMapOk<Self, U, F>
+    where
+        F: FnMut(O) -> Result<U, E>,
+        U: IntoIterator<Item = O2>,
+    {
+        TryFlatMapOk {
+            frontiter: None,
+            backiter: None,
+            iter: self,
+            f,
+        }
+    }
+
+    #[inline]
+    fn recover_with<U, F>(self, f: F) -> RecoverWith<Self, U, F>
+    where
+        F: FnMut(E) -> U,
+        U: IntoIterator<Item = O>,
+    {
+        RecoverWith {
+            frontiter: None,
+            backiter: None,
             iter: self,
             f,
         }
@@ -80,10 +159,43 @@ where
     U: IntoIterator,
 {
     frontiter: Option<<U as IntoIterator>::IntoIter>,
+    backiter: Option<<U as IntoIterator>::IntoIter>,
     iter: I,
     f: F,
 }
 
+impl<I, U, F> Clone for FlatMapOk<I, U, F>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        FlatMapOk {
+            frontiter: self.frontiter.clone(),
+            backiter: self.backiter.clone(),
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, U, F> fmt::Debug for FlatMapOk<I, U, F>
+where
+    I: fmt::Debug,
+    U: IntoIterator,
+    U::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlatMapOk")
+            .field("iter", &self.iter)
+            .field("frontiter", &self.frontiter)
+            .field("backiter", &self.backiter)
+            .finish()
+    }
+}
+
 impl<I, O, E, F, O2, U> Iterator for FlatMapOk<I, U, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -98,9 +210,10 @@ where
                 if let elt @ Some(_) = inner.next() {
                     return elt.map(Ok);
                 }
+                self.frontiter = None;
             }
             match self.iter.next() {
-                None => return None,
+                None => return and_then_or_clear(&mut self.backiter, Iterator::next).map(Ok),
                 Some(Ok(x)) => {
                     self.frontiter = Some((self.f)(x).into_iter());
                 }
@@ -110,20 +223,112 @@ where
     }
 
     #[inline]
-    // TODO: Oh dear, this hint could be much better
-    // https://doc.rust-lang.org/src/core/iter/mod.rs.html#2694
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        flatten_size_hint(
+            self.frontiter.as_ref(),
+            self.backiter.as_ref(),
+            self.iter.size_hint(),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(front) = self.frontiter {
+            acc = front.fold(acc, |acc, x| g(acc, Ok(x)));
+        }
+        let mut f = self.f;
+        acc = self.iter.fold(acc, |acc, r| match r {
+            Ok(x) => f(x).into_iter().fold(acc, |acc, x| g(acc, Ok(x))),
+            Err(x) => g(acc, Err(x)),
+        });
+        if let Some(back) = self.backiter {
+            acc = back.fold(acc, |acc, x| g(acc, Ok(x)));
+        }
+        acc
+    }
+}
+
+impl<I, O, E, F, O2, U> DoubleEndedIterator for FlatMapOk<I, U, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> U,
+    U: IntoIterator<Item = O2>,
+    U::IntoIter: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.backiter {
+                if let elt @ Some(_) = inner.next_back() {
+                    return elt.map(Ok);
+                }
+                self.backiter = None;
+            }
+            match self.iter.next_back() {
+                None => {
+                    return and_then_or_clear(&mut self.frontiter, DoubleEndedIterator::next_back)
+                        .map(Ok)
+                }
+                Some(Ok(x)) => {
+                    self.backiter = Some((self.f)(x).into_iter());
+                }
+                Some(Err(x)) => return Some(Err(x)),
+            }
+        }
     }
 }
 
+impl<I, O, E, F, O2, U> FusedIterator for FlatMapOk<I, U, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> U,
+    U: IntoIterator<Item = O2>,
+{
+}
+
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct FlatMapErr<I, U: IntoIterator, F> {
     frontiter: Option<<U as IntoIterator>::IntoIter>,
+    backiter: Option<<U as IntoIterator>::IntoIter>,
     iter: I,
     f: F,
 }
 
+impl<I, U, F> Clone for FlatMapErr<I, U, F>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        FlatMapErr {
+            frontiter: self.frontiter.clone(),
+            backiter: self.backiter.clone(),
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, U, F> fmt::Debug for FlatMapErr<I, U, F>
+where
+    I: fmt::Debug,
+    U: IntoIterator,
+    U::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlatMapErr")
+            .field("iter", &self.iter)
+            .field("frontiter", &self.frontiter)
+            .field("backiter", &self.backiter)
+            .finish()
+    }
+}
+
 impl<I, O, E, F, E2, U> Iterator for FlatMapErr<I, U, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -138,9 +343,10 @@ where
                 if let elt @ Some(_) = inner.next() {
                     return elt.map(Err);
                 }
+                self.frontiter = None;
             }
             match self.iter.next() {
-                None => return None,
+                None => return and_then_or_clear(&mut self.backiter, Iterator::next).map(Err),
                 Some(Err(e)) => {
                     self.frontiter = Some((self.f)(e).into_iter());
                 }
@@ -151,6 +357,442 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        flatten_size_hint(
+            self.frontiter.as_ref(),
+            self.backiter.as_ref(),
+            self.iter.size_hint(),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(front) = self.frontiter {
+            acc = front.fold(acc, |acc, x| g(acc, Err(x)));
+        }
+        let mut f = self.f;
+        acc = self.iter.fold(acc, |acc, r| match r {
+            Err(x) => f(x).into_iter().fold(acc, |acc, x| g(acc, Err(x))),
+            Ok(x) => g(acc, Ok(x)),
+        });
+        if let Some(back) = self.backiter {
+            acc = back.fold(acc, |acc, x| g(acc, Err(x)));
+        }
+        acc
+    }
+}
+
+impl<I, O, E, F, E2, U> DoubleEndedIterator for FlatMapErr<I, U, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> U,
+    U: IntoIterator<Item = E2>,
+    U::IntoIter: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.backiter {
+                if let elt @ Some(_) = inner.next_back() {
+                    return elt.map(Err);
+                }
+                self.backiter = None;
+            }
+            match self.iter.next_back() {
+                None => {
+                    return and_then_or_clear(&mut self.frontiter, DoubleEndedIterator::next_back)
+                        .map(Err)
+                }
+                Some(Err(x)) => {
+                    self.backiter = Some((self.f)(x).into_iter());
+                }
+                Some(Ok(x)) => return Some(Ok(x)),
+            }
+        }
+    }
+}
+
+impl<I, O, E, F, E2, U> FusedIterator for FlatMapErr<I, U, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> U,
+    U: IntoIterator<Item = E2>,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TryFlatMapOk<I, U: IntoIterator, F> {
+    frontiter: Option<<U as IntoIterator>::IntoIter>,
+    backiter: Option<<U as IntoIterator>::IntoIter>,
+    iter: I,
+    f: F,
+}
+
+impl<I, U, F> Clone for TryFlatMapOk<I, U, F>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        TryFlatMapOk {
+            frontiter: self.frontiter.clone(),
+            backiter: self.backiter.clone(),
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, U, F> fmt::Debug for TryFlatMapOk<I, U, F>
+where
+    I: fmt::Debug,
+    U: IntoIterator,
+    U::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TryFlatMapOk")
+            .field("iter", &self.iter)
+            .field("frontiter", &self.frontiter)
+            .field("backiter", &self.backiter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, O2, U> Iterator for TryFlatMapOk<I, U, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<U, E>,
+    U: IntoIterator<Item = O2>,
+{
+    type Item = Result<O2, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.frontiter {
+                if let elt @ Some(_) = inner.next() {
+                    return elt.map(Ok);
+                }
+                self.frontiter = None;
+            }
+            match self.iter.next() {
+                None => return and_then_or_clear(&mut self.backiter, Iterator::next).map(Ok),
+                Some(Ok(x)) => match (self.f)(x) {
+                    Ok(u) => self.frontiter = Some(u.into_iter()),
+                    Err(e) => return Some(Err(e)),
+                },
+                Some(Err(e)) => return Some(Err(e)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        flatten_size_hint(
+            self.frontiter.as_ref(),
+            self.backiter.as_ref(),
+            self.iter.size_hint(),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(front) = self.frontiter {
+            acc = front.fold(acc, |acc, x| g(acc, Ok(x)));
+        }
+        let mut f = self.f;
+        acc = self.iter.fold(acc, |acc, r| match r.and_then(&mut f) {
+            Ok(u) => u.into_iter().fold(acc, |acc, x| g(acc, Ok(x))),
+            Err(e) => g(acc, Err(e)),
+        });
+        if let Some(back) = self.backiter {
+            acc = back.fold(acc, |acc, x| g(acc, Ok(x)));
+        }
+        acc
+    }
+}
+
+impl<I, O, E, F, O2, U> DoubleEndedIterator for TryFlatMapOk<I, U, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<U, E>,
+    U: IntoIterator<Item = O2>,
+    U::IntoIter: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.backiter {
+                if let elt @ Some(_) = inner.next_back() {
+                    return elt.map(Ok);
+                }
+                self.backiter = None;
+            }
+            match self.iter.next_back() {
+                None => {
+                    return and_then_or_clear(&mut self.frontiter, DoubleEndedIterator::next_back)
+                        .map(Ok)
+                }
+                Some(Ok(x)) => match (self.f)(x) {
+                    Ok(u) => self.backiter = Some(u.into_iter()),
+                    Err(e) => return Some(Err(e)),
+                },
+                Some(Err(e)) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<I, O, E, F, O2, U> FusedIterator for TryFlatMapOk<I, U, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<U, E>,
+    U: IntoIterator<Item = O2>,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct RecoverWith<I, U: IntoIterator, F> {
+    frontiter: Option<<U as IntoIterator>::IntoIter>,
+    backiter: Option<<U as IntoIterator>::IntoIter>,
+    iter: I,
+    f: F,
+}
+
+impl<I, U, F> Clone for RecoverWith<I, U, F>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        RecoverWith {
+            frontiter: self.frontiter.clone(),
+            backiter: self.backiter.clone(),
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+        }
+    }
+}
+
+impl<I, U, F> fmt::Debug for RecoverWith<I, U, F>
+where
+    I: fmt::Debug,
+    U: IntoIterator,
+    U::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RecoverWith")
+            .field("iter", &self.iter)
+            .field("frontiter", &self.frontiter)
+            .field("backiter", &self.backiter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, U> Iterator for RecoverWith<I, U, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(E) -> U,
+    U: IntoIterator<Item = O>,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.frontiter {
+                if let elt @ Some(_) = inner.next() {
+                    return elt;
+                }
+                self.frontiter = None;
+            }
+            match self.iter.next() {
+                None => return and_then_or_clear(&mut self.backiter, Iterator::next),
+                Some(Err(e)) => {
+                    self.frontiter = Some((self.f)(e).into_iter());
+                }
+                Some(Ok(o)) => return Some(o),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        flatten_size_hint(
+            self.frontiter.as_ref(),
+            self.backiter.as_ref(),
+            self.iter.size_hint(),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(front) = self.frontiter {
+            acc = front.fold(acc, &mut g);
+        }
+        let mut f = self.f;
+        acc = self.iter.fold(acc, |acc, r| match r {
+            Err(e) => f(e).into_iter().fold(acc, &mut g),
+            Ok(o) => g(acc, o),
+        });
+        if let Some(back) = self.backiter {
+            acc = back.fold(acc, &mut g);
+        }
+        acc
+    }
+}
+
+impl<I, O, E, F, U> DoubleEndedIterator for RecoverWith<I, U, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> U,
+    U: IntoIterator<Item = O>,
+    U::IntoIter: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.backiter {
+                if let elt @ Some(_) = inner.next_back() {
+                    return elt;
+                }
+                self.backiter = None;
+            }
+            match self.iter.next_back() {
+                None => {
+                    return and_then_or_clear(&mut self.frontiter, DoubleEndedIterator::next_back)
+                }
+                Some(Err(e)) => {
+                    self.backiter = Some((self.f)(e).into_iter());
+                }
+                Some(Ok(o)) => return Some(o),
+            }
+        }
+    }
+}
+
+impl<I, O, E, F, U> FusedIterator for RecoverWith<I, U, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> U,
+    U: IntoIterator<Item = O>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_map_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(2), Err(2), Ok(3)];
+        let res: Vec<_> = v.into_iter().flat_map_ok(|i| 0..i).rev().collect();
+
+        assert_eq!(res, vec![Ok(2), Ok(1), Ok(0), Err(2), Ok(1), Ok(0), Ok(0)]);
+    }
+
+    #[test]
+    fn test_flat_map_ok_both_ends() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(3), Err(7), Ok(2)];
+        let mut iter = v.into_iter().flat_map_ok(|i| 0..i);
+
+        assert_eq!(iter.next(), Some(Ok(0)));
+        assert_eq!(iter.next_back(), Some(Ok(1)));
+        assert_eq!(iter.next_back(), Some(Ok(0)));
+        assert_eq!(iter.next_back(), Some(Err(7)));
+        assert_eq!(iter.next_back(), Some(Ok(2)));
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_flat_map_err_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2), Err(2)];
+        let res: Vec<_> = v.into_iter().flat_map_err(|i| 0..i).rev().collect();
+
+        assert_eq!(res, vec![Err(1), Err(0), Ok(2), Err(0)]);
+    }
+
+    #[test]
+    fn test_flat_map_ok_fused() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(2)];
+        let mut iter = v.into_iter().flat_map_ok(|i| 0..i);
+
+        assert_eq!(iter.next_back(), Some(Ok(1)));
+        assert_eq!(iter.next(), Some(Ok(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_flat_map_ok_fold_after_partial_iteration() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(3), Err(7), Ok(3)];
+        let mut iter = v.into_iter().flat_map_ok(|i| 0..i);
+
+        assert_eq!(iter.next(), Some(Ok(0)));
+        assert_eq!(iter.next_back(), Some(Ok(2)));
+
+        let rest = iter.fold(Vec::new(), |mut v, r| {
+            v.push(r);
+            v
+        });
+        assert_eq!(rest, vec![Ok(1), Ok(2), Err(7), Ok(0), Ok(1)]);
+    }
+
+    #[test]
+    fn test_flat_map_ok_clone() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(2), Err(1), Ok(1)];
+        let mut iter = v.into_iter().flat_map_ok(|i| 0..i);
+        assert_eq!(iter.next(), Some(Ok(0)));
+
+        let cloned: Vec<_> = iter.clone().collect();
+        assert_eq!(cloned, vec![Ok(1), Err(1), Ok(0)]);
+        assert_eq!(iter.collect::<Vec<_>>(), cloned);
+    }
+
+    #[test]
+    fn test_flat_map_ok_hint() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(3), Err(1)];
+        let mut iter = v.into_iter().flat_map_ok(|i| 0..i);
+        assert_eq!(iter.size_hint(), (0, None));
+
+        assert_eq!(iter.next(), Some(Ok(0)));
+        assert_eq!(iter.size_hint(), (2, None));
+
+        assert_eq!(iter.next_back(), Some(Err(1)));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![Ok(1), Ok(2)]);
+    }
+
+    #[test]
+    fn test_try_flat_map_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(2), Ok(12), Err(3), Ok(1)];
+        let res: Vec<_> = v
+            .into_iter()
+            .try_flat_map_ok(|i| if i < 10 { Ok(0..i) } else { Err(i) })
+            .rev()
+            .collect();
+
+        assert_eq!(res, vec![Ok(0), Err(3), Err(12), Ok(1), Ok(0)]);
+    }
+
+    #[test]
+    fn test_recover_with_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Err(2), Ok(7), Err(3)];
+        let res: Vec<_> = v.into_iter().recover_with(|i| 0..i).rev().collect();
+
+        assert_eq!(res, vec![2, 1, 0, 7, 1, 0]);
     }
 }
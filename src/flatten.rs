@@ -4,6 +4,11 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
+use util::{and_then_or_clear, flatten_size_hint};
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform Oks and Errors.
 pub trait Flatten<O, E>: Sized {
     /// [flatten](Iterator::flatten) `Ok` values while leaving `Err`-values as is.
@@ -69,6 +74,7 @@ where
     {
         FlattenOk {
             frontiter: None,
+            backiter: None,
             iter: self,
         }
     }
@@ -80,6 +86,7 @@ where
     {
         FlattenErr {
             frontiter: None,
+            backiter: None,
             iter: self,
         }
     }
@@ -91,9 +98,40 @@ where
     U: IntoIterator,
 {
     frontiter: Option<<U as IntoIterator>::IntoIter>,
+    backiter: Option<<U as IntoIterator>::IntoIter>,
     iter: I,
 }
 
+impl<I, U> Clone for FlattenOk<I, U>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        FlattenOk {
+            frontiter: self.frontiter.clone(),
+            backiter: self.backiter.clone(),
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<I, U> fmt::Debug for FlattenOk<I, U>
+where
+    I: fmt::Debug,
+    U: IntoIterator,
+    U::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlattenOk")
+            .field("iter", &self.iter)
+            .field("frontiter", &self.frontiter)
+            .field("backiter", &self.backiter)
+            .finish()
+    }
+}
+
 impl<I, E, O2, U> Iterator for FlattenOk<I, U>
 where
     I: Iterator<Item = Result<U, E>>,
@@ -107,9 +145,10 @@ where
                 if let elt @ Some(_) = inner.next() {
                     return elt.map(Ok);
                 }
+                self.frontiter = None;
             }
             match self.iter.next() {
-                None => return None,
+                None => return and_then_or_clear(&mut self.backiter, Iterator::next).map(Ok),
                 Some(Ok(x)) => {
                     self.frontiter = Some(x.into_iter());
                 }
@@ -119,19 +158,106 @@ where
     }
 
     #[inline]
-    // TODO: Oh dear, this hint could be much better
-    // https://doc.rust-lang.org/src/core/iter/mod.rs.html#2694
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        flatten_size_hint(
+            self.frontiter.as_ref(),
+            self.backiter.as_ref(),
+            self.iter.size_hint(),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(front) = self.frontiter {
+            acc = front.fold(acc, |acc, x| g(acc, Ok(x)));
+        }
+        acc = self.iter.fold(acc, |acc, r| match r {
+            Ok(x) => x.into_iter().fold(acc, |acc, x| g(acc, Ok(x))),
+            Err(x) => g(acc, Err(x)),
+        });
+        if let Some(back) = self.backiter {
+            acc = back.fold(acc, |acc, x| g(acc, Ok(x)));
+        }
+        acc
     }
 }
 
+impl<I, E, O2, U> DoubleEndedIterator for FlattenOk<I, U>
+where
+    I: DoubleEndedIterator<Item = Result<U, E>>,
+    U: IntoIterator<Item = O2>,
+    U::IntoIter: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.backiter {
+                if let elt @ Some(_) = inner.next_back() {
+                    return elt.map(Ok);
+                }
+                self.backiter = None;
+            }
+            match self.iter.next_back() {
+                None => {
+                    return and_then_or_clear(&mut self.frontiter, DoubleEndedIterator::next_back)
+                        .map(Ok)
+                }
+                Some(Ok(x)) => {
+                    self.backiter = Some(x.into_iter());
+                }
+                Some(Err(x)) => return Some(Err(x)),
+            }
+        }
+    }
+}
+
+impl<I, E, O2, U> FusedIterator for FlattenOk<I, U>
+where
+    I: FusedIterator<Item = Result<U, E>>,
+    U: IntoIterator<Item = O2>,
+{
+}
+
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct FlattenErr<I, U: IntoIterator> {
     frontiter: Option<<U as IntoIterator>::IntoIter>,
+    backiter: Option<<U as IntoIterator>::IntoIter>,
     iter: I,
 }
 
+impl<I, U> Clone for FlattenErr<I, U>
+where
+    I: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        FlattenErr {
+            frontiter: self.frontiter.clone(),
+            backiter: self.backiter.clone(),
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<I, U> fmt::Debug for FlattenErr<I, U>
+where
+    I: fmt::Debug,
+    U: IntoIterator,
+    U::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlattenErr")
+            .field("iter", &self.iter)
+            .field("frontiter", &self.frontiter)
+            .field("backiter", &self.backiter)
+            .finish()
+    }
+}
+
 impl<I, O, E2, U> Iterator for FlattenErr<I, U>
 where
     I: Iterator<Item = Result<O, U>>,
@@ -145,9 +271,10 @@ where
                 if let elt @ Some(_) = inner.next() {
                     return elt.map(Err);
                 }
+                self.frontiter = None;
             }
             match self.iter.next() {
-                None => return None,
+                None => return and_then_or_clear(&mut self.backiter, Iterator::next).map(Err),
                 Some(Err(e)) => {
                     self.frontiter = Some(e.into_iter());
                 }
@@ -156,8 +283,209 @@ where
         }
     }
 
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        flatten_size_hint(
+            self.frontiter.as_ref(),
+            self.backiter.as_ref(),
+            self.iter.size_hint(),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut acc = init;
+        if let Some(front) = self.frontiter {
+            acc = front.fold(acc, |acc, x| g(acc, Err(x)));
+        }
+        acc = self.iter.fold(acc, |acc, r| match r {
+            Err(x) => x.into_iter().fold(acc, |acc, x| g(acc, Err(x))),
+            Ok(x) => g(acc, Ok(x)),
+        });
+        if let Some(back) = self.backiter {
+            acc = back.fold(acc, |acc, x| g(acc, Err(x)));
+        }
+        acc
+    }
+}
+
+impl<I, O, E2, U> DoubleEndedIterator for FlattenErr<I, U>
+where
+    I: DoubleEndedIterator<Item = Result<O, U>>,
+    U: IntoIterator<Item = E2>,
+    U::IntoIter: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut inner) = self.backiter {
+                if let elt @ Some(_) = inner.next_back() {
+                    return elt.map(Err);
+                }
+                self.backiter = None;
+            }
+            match self.iter.next_back() {
+                None => {
+                    return and_then_or_clear(&mut self.frontiter, DoubleEndedIterator::next_back)
+                        .map(Err)
+                }
+                Some(Err(x)) => {
+                    self.backiter = Some(x.into_iter());
+                }
+                Some(Ok(x)) => return Some(Ok(x)),
+            }
+        }
+    }
+}
+
+impl<I, O, E2, U> FusedIterator for FlattenErr<I, U>
+where
+    I: FusedIterator<Item = Result<O, U>>,
+    U: IntoIterator<Item = E2>,
+{
+}
+
+/// Extension trait for `Iterator<Item = Result<Result<O, E2>, E>>` to flatten nested results
+pub trait FlattenNested<O, E2, E>: Sized {
+    /// Flatten one level of nested `Result`s, converting inner errors via `Into`
+    ///
+    /// ```
+    /// use resiter::flatten::FlattenNested;
+    /// use resiter::map::Map;
+    /// use std::str::FromStr;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Read(&'static str),
+    ///     Parse,
+    /// }
+    ///
+    /// impl From<std::num::ParseIntError> for Error {
+    ///     fn from(_: std::num::ParseIntError) -> Self {
+    ///         Error::Parse
+    ///     }
+    /// }
+    ///
+    /// let lines: Vec<Result<&str, Error>> = vec![Ok("1"), Err(Error::Read("disk")), Ok("a")];
+    /// let parsed: Vec<Result<usize, Error>> = lines
+    ///     .into_iter()
+    ///     .map_ok(usize::from_str)
+    ///     .flatten_results()
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed, vec![Ok(1), Err(Error::Read("disk")), Err(Error::Parse)]);
+    /// ```
+    fn flatten_results(self) -> FlattenResults<Self>
+    where
+        E2: Into<E>;
+}
+
+impl<I, O, E2, E> FlattenNested<O, E2, E> for I
+where
+    I: Iterator<Item = Result<Result<O, E2>, E>> + Sized,
+{
+    #[inline]
+    fn flatten_results(self) -> FlattenResults<Self>
+    where
+        E2: Into<E>,
+    {
+        FlattenResults { iter: self }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct FlattenResults<I> {
+    iter: I,
+}
+
+impl<I, O, E2, E> Iterator for FlattenResults<I>
+where
+    I: Iterator<Item = Result<Result<O, E2>, E>>,
+    E2: Into<E>,
+{
+    type Item = Result<O, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|r| r.and_then(|inner| inner.map_err(Into::into)))
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, r| {
+            g(acc, r.and_then(|inner| inner.map_err(Into::into)))
+        })
+    }
+}
+
+impl<I, O, E2, E> DoubleEndedIterator for FlattenResults<I>
+where
+    I: DoubleEndedIterator<Item = Result<Result<O, E2>, E>>,
+    E2: Into<E>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|r| r.and_then(|inner| inner.map_err(Into::into)))
+    }
+}
+
+impl<I, O, E2, E> FusedIterator for FlattenResults<I>
+where
+    I: FusedIterator<Item = Result<Result<O, E2>, E>>,
+    E2: Into<E>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_ok_rev() {
+        let v: Vec<Result<Vec<usize>, usize>> = vec![Ok(vec![1, 2]), Err(3), Ok(vec![4])];
+        let res: Vec<_> = v.into_iter().flatten_ok().rev().collect();
+
+        assert_eq!(res, vec![Ok(4), Err(3), Ok(2), Ok(1)]);
+    }
+
+    #[test]
+    fn test_flatten_err_rev() {
+        let v: Vec<Result<usize, Vec<usize>>> = vec![Err(vec![1, 2]), Ok(3), Err(vec![4])];
+        let res: Vec<_> = v.into_iter().flatten_err().rev().collect();
+
+        assert_eq!(res, vec![Err(4), Ok(3), Err(2), Err(1)]);
+    }
+
+    #[test]
+    fn test_flatten_ok_hint() {
+        let v: Vec<Result<Vec<usize>, usize>> = vec![Ok(vec![1, 2, 3]), Err(4), Ok(vec![5, 6])];
+        let mut iter = v.into_iter().flatten_ok();
+        assert_eq!(iter.size_hint(), (0, None));
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.size_hint(), (2, None));
+
+        assert_eq!(iter.next_back(), Some(Ok(6)));
+        assert_eq!(iter.size_hint(), (3, None));
+
+        assert_eq!(iter.next(), Some(Ok(2)));
+        assert_eq!(iter.next(), Some(Ok(3)));
+        assert_eq!(iter.next(), Some(Err(4)));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+    }
 }
@@ -0,0 +1,129 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to fold over all `Ok` values.
+pub trait FoldOk<O, E>: Sized {
+    /// Fold all `Ok` values into an accumulator, stopping at the first `Err`
+    ///
+    /// ```
+    /// use resiter::fold::FoldOk;
+    /// use std::str::FromStr;
+    ///
+    /// let sum = ["1", "2", "3", "4", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .fold_ok(0, |acc, i| acc + i);
+    ///
+    /// assert_eq!(sum, Ok(15));
+    /// ```
+    ///
+    /// The first error is returned and the rest of the iterator is not consumed:
+    ///
+    /// ```
+    /// use resiter::fold::FoldOk;
+    /// use std::str::FromStr;
+    ///
+    /// let mut iter = ["1", "2", "a", "4", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt));
+    ///
+    /// let sum = iter.by_ref().fold_ok(0, |acc, i| acc + i);
+    ///
+    /// assert!(sum.is_err());
+    /// assert_eq!(iter.next(), Some(Ok(4)));
+    /// ```
+    fn fold_ok<Acc, F>(self, init: Acc, f: F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, O) -> Acc;
+
+    /// Reduce all `Ok` values to a single one by repeatedly applying `f`, stopping at the first
+    /// `Err`
+    ///
+    /// Returns `Ok(None)` if the iterator is empty.
+    ///
+    /// ```
+    /// use resiter::fold::FoldOk;
+    /// use std::str::FromStr;
+    ///
+    /// let max = ["1", "5", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .reduce_ok(|a, b| a.max(b));
+    ///
+    /// assert_eq!(max, Ok(Some(5)));
+    ///
+    /// let max = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .reduce_ok(|a, b| a.max(b));
+    ///
+    /// assert!(max.is_err());
+    /// ```
+    fn reduce_ok<F>(self, f: F) -> Result<Option<O>, E>
+    where
+        F: FnMut(O, O) -> O;
+
+    /// Reduce all `Ok` values to a single one by repeatedly applying the fallible `f`, stopping at
+    /// the first `Err` of either the iterator or `f`
+    ///
+    /// ```
+    /// use resiter::fold::FoldOk;
+    ///
+    /// let sum = vec![Ok(1u8), Ok(2), Ok(3)]
+    ///     .into_iter()
+    ///     .try_reduce_ok(|a, b| a.checked_add(b).ok_or("overflow"));
+    ///
+    /// assert_eq!(sum, Ok(Some(6)));
+    ///
+    /// let sum = vec![Ok(200u8), Ok(100), Err("broken")]
+    ///     .into_iter()
+    ///     .try_reduce_ok(|a, b| a.checked_add(b).ok_or("overflow"));
+    ///
+    /// assert_eq!(sum, Err("overflow"));
+    /// ```
+    fn try_reduce_ok<F>(self, f: F) -> Result<Option<O>, E>
+    where
+        F: FnMut(O, O) -> Result<O, E>;
+}
+
+impl<I, O, E> FoldOk<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn fold_ok<Acc, F>(self, init: Acc, mut f: F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, O) -> Acc,
+    {
+        let mut acc = init;
+        for res in self {
+            acc = f(acc, res?);
+        }
+        Ok(acc)
+    }
+
+    #[inline]
+    fn reduce_ok<F>(self, mut f: F) -> Result<Option<O>, E>
+    where
+        F: FnMut(O, O) -> O,
+    {
+        self.try_reduce_ok(|a, b| Ok(f(a, b)))
+    }
+
+    fn try_reduce_ok<F>(mut self, mut f: F) -> Result<Option<O>, E>
+    where
+        F: FnMut(O, O) -> Result<O, E>,
+    {
+        let mut acc = match self.next() {
+            Some(res) => res?,
+            None => return Ok(None),
+        };
+        for res in self {
+            acc = f(acc, res?)?;
+        }
+        Ok(Some(acc))
+    }
+}
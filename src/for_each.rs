@@ -0,0 +1,93 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to run a fallible action on one kind of
+/// result
+pub trait TryForEach<O, E>: Sized {
+    /// Call `f` on every `Ok` value, stopping at the first `Err` of either the iterator or `f`
+    ///
+    /// Errors of the iterator are converted into the error type of `f` via `From`.
+    ///
+    /// ```
+    /// use resiter::for_each::TryForEach;
+    /// use std::str::FromStr;
+    ///
+    /// let mut stored = Vec::new();
+    /// let res = ["1", "2", "30", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt).map_err(|e| e.to_string()))
+    ///     .try_for_each_ok(|i| {
+    ///         if i > 10 {
+    ///             return Err(format!("{} is too large", i));
+    ///         }
+    ///         stored.push(i);
+    ///         Ok(())
+    ///     });
+    ///
+    /// assert_eq!(res, Err("30 is too large".to_string()));
+    /// assert_eq!(stored, vec![1, 2]);
+    /// ```
+    fn try_for_each_ok<F, E2>(self, _: F) -> Result<(), E2>
+    where
+        F: FnMut(O) -> Result<(), E2>,
+        E2: From<E>;
+
+    /// Call `f` on every `Err` value, skipping all `Ok` values, and stop at the first error
+    /// returned by `f`
+    ///
+    /// ```
+    /// use resiter::for_each::TryForEach;
+    /// use std::str::FromStr;
+    ///
+    /// let mut reported = 0;
+    /// let res = ["1", "a", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .try_for_each_err(|_| {
+    ///         if reported == 1 {
+    ///             return Err("too many errors");
+    ///         }
+    ///         reported += 1;
+    ///         Ok(())
+    ///     });
+    ///
+    /// assert_eq!(res, Err("too many errors"));
+    /// assert_eq!(reported, 1);
+    /// ```
+    fn try_for_each_err<F, E2>(self, _: F) -> Result<(), E2>
+    where
+        F: FnMut(E) -> Result<(), E2>;
+}
+
+impl<I, O, E> TryForEach<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn try_for_each_ok<F, E2>(self, mut f: F) -> Result<(), E2>
+    where
+        F: FnMut(O) -> Result<(), E2>,
+        E2: From<E>,
+    {
+        for res in self {
+            f(res?)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn try_for_each_err<F, E2>(self, mut f: F) -> Result<(), E2>
+    where
+        F: FnMut(E) -> Result<(), E2>,
+    {
+        for res in self {
+            if let Err(e) = res {
+                f(e)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to look at every item as a whole
+pub trait Inspect<O, E>: Sized {
+    /// Call a closure with a reference to every `Result` before it is yielded
+    ///
+    /// Other than stacking `on_ok` and `on_err`, a single closure sees both variants, so shared
+    /// state does not need to be threaded through two closures.
+    ///
+    /// ```
+    /// use resiter::inspect::Inspect;
+    /// use std::str::FromStr;
+    ///
+    /// let mut seen = 0;
+    /// let mut failed = 0;
+    /// let oks: Vec<_> = ["1", "a", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .inspect_result(|r| {
+    ///         seen += 1;
+    ///         if r.is_err() {
+    ///             failed += 1;
+    ///         }
+    ///     })
+    ///     .filter_map(Result::ok)
+    ///     .collect();
+    ///
+    /// assert_eq!(oks, vec![1, 3]);
+    /// assert_eq!((seen, failed), (4, 2));
+    /// ```
+    fn inspect_result<F>(self, _: F) -> InspectResult<Self, F>
+    where
+        F: FnMut(&Result<O, E>);
+}
+
+impl<I, O, E> Inspect<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn inspect_result<F>(self, f: F) -> InspectResult<Self, F>
+    where
+        F: FnMut(&Result<O, E>),
+    {
+        InspectResult { iter: self, f }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct InspectResult<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for InspectResult<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("InspectResult")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F> Iterator for InspectResult<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&Result<O, E>),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        (self.f)(&r);
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| {
+            f(&r);
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for InspectResult<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&Result<O, E>),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        (self.f)(&r);
+        Some(r)
+    }
+}
+
+impl<I, O, E, F> FusedIterator for InspectResult<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&Result<O, E>),
+{
+}
@@ -0,0 +1,722 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions for `Iterator<Item = io::Result<O>>`, as well as for the common sources of such
+//! iterators, `BufRead::lines()` and `fs::read_dir()`, and for writing errors to an `io::Write`
+//!
+//! Only available with the `std` feature.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use std::fs::ReadDir;
+use std::io::{self, BufRead, ErrorKind, Lines};
+use std::path::PathBuf;
+use std::string::String;
+
+/// Extension trait for `Iterator<Item = io::Result<O>>` to handle errors by their `ErrorKind`
+pub trait IoResults<O>: Sized {
+    /// Drop all `Err` items of the given `kind`, while keeping `Ok` and all other errors
+    ///
+    /// ```
+    /// use resiter::io::IoResults;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let res: Vec<_> = vec![
+    ///     Ok("a.txt"),
+    ///     Err(Error::new(ErrorKind::NotFound, "b.txt")),
+    ///     Err(Error::new(ErrorKind::PermissionDenied, "c.txt")),
+    /// ]
+    /// .into_iter()
+    /// .ignore_kind(ErrorKind::NotFound)
+    /// .collect();
+    ///
+    /// assert_eq!(res.len(), 2);
+    /// assert_eq!(res[0].as_ref().unwrap(), &"a.txt");
+    /// assert_eq!(res[1].as_ref().unwrap_err().kind(), ErrorKind::PermissionDenied);
+    /// ```
+    fn ignore_kind(self, kind: ErrorKind) -> IgnoreKind<Self>;
+
+    /// Filter `Err` items by their `ErrorKind` while keeping `Ok`
+    ///
+    /// Errors for which `f` returns `false` are dropped.
+    ///
+    /// ```
+    /// use resiter::io::IoResults;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let res: Vec<_> = vec![
+    ///     Ok(1),
+    ///     Err(Error::new(ErrorKind::NotFound, "gone")),
+    ///     Err(Error::new(ErrorKind::AlreadyExists, "there")),
+    ///     Err(Error::new(ErrorKind::PermissionDenied, "nope")),
+    /// ]
+    /// .into_iter()
+    /// .filter_err_kind(|kind| kind == ErrorKind::PermissionDenied)
+    /// .collect();
+    ///
+    /// assert_eq!(res.len(), 2);
+    /// assert_eq!(res[1].as_ref().unwrap_err().kind(), ErrorKind::PermissionDenied);
+    /// ```
+    fn filter_err_kind<F>(self, _: F) -> FilterErrKind<Self, F>
+    where
+        F: FnMut(ErrorKind) -> bool;
+
+    /// Pull the next item again whenever an `ErrorKind::Interrupted` error is encountered
+    ///
+    /// Interrupted operations can usually be retried, which for iterators such as
+    /// `BufRead::lines` means calling `next()` once more. All other errors are yielded.
+    ///
+    /// ```
+    /// use resiter::io::IoResults;
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let res: Vec<_> = vec![
+    ///     Ok(1),
+    ///     Err(Error::new(ErrorKind::Interrupted, "signal")),
+    ///     Ok(2),
+    /// ]
+    /// .into_iter()
+    /// .retry_interrupted()
+    /// .map(Result::unwrap)
+    /// .collect();
+    ///
+    /// assert_eq!(res, vec![1, 2]);
+    /// ```
+    fn retry_interrupted(self) -> IgnoreKind<Self>;
+}
+
+impl<I, O> IoResults<O> for I
+where
+    I: Iterator<Item = io::Result<O>> + Sized,
+{
+    #[inline]
+    fn ignore_kind(self, kind: ErrorKind) -> IgnoreKind<Self> {
+        IgnoreKind { iter: self, kind }
+    }
+
+    #[inline]
+    fn filter_err_kind<F>(self, f: F) -> FilterErrKind<Self, F>
+    where
+        F: FnMut(ErrorKind) -> bool,
+    {
+        FilterErrKind { iter: self, f }
+    }
+
+    #[inline]
+    fn retry_interrupted(self) -> IgnoreKind<Self> {
+        self.ignore_kind(ErrorKind::Interrupted)
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct IgnoreKind<I> {
+    iter: I,
+    kind: ErrorKind,
+}
+
+impl<I, O> Iterator for IgnoreKind<I>
+where
+    I: Iterator<Item = io::Result<O>>,
+{
+    type Item = io::Result<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Err(ref e) if e.kind() == self.kind => continue,
+                other => return Some(other),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let kind = self.kind;
+        self.iter.fold(init, move |acc, r| match r {
+            Err(ref e) if e.kind() == kind => acc,
+            other => g(acc, other),
+        })
+    }
+}
+
+impl<I, O> DoubleEndedIterator for IgnoreKind<I>
+where
+    I: DoubleEndedIterator<Item = io::Result<O>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back()? {
+                Err(ref e) if e.kind() == self.kind => continue,
+                other => return Some(other),
+            }
+        }
+    }
+}
+
+impl<I, O> FusedIterator for IgnoreKind<I> where I: FusedIterator<Item = io::Result<O>> {}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct FilterErrKind<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for FilterErrKind<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterErrKind")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, F> Iterator for FilterErrKind<I, F>
+where
+    I: Iterator<Item = io::Result<O>>,
+    F: FnMut(ErrorKind) -> bool,
+{
+    type Item = io::Result<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Err(e) => {
+                    if (self.f)(e.kind()) {
+                        return Some(Err(e));
+                    }
+                }
+                ok => return Some(ok),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Err(e) => {
+                if f(e.kind()) {
+                    g(acc, Err(e))
+                } else {
+                    acc
+                }
+            }
+            ok => g(acc, ok),
+        })
+    }
+}
+
+impl<I, O, F> DoubleEndedIterator for FilterErrKind<I, F>
+where
+    I: DoubleEndedIterator<Item = io::Result<O>>,
+    F: FnMut(ErrorKind) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back()? {
+                Err(e) => {
+                    if (self.f)(e.kind()) {
+                        return Some(Err(e));
+                    }
+                }
+                ok => return Some(ok),
+            }
+        }
+    }
+}
+
+impl<I, O, F> FusedIterator for FilterErrKind<I, F>
+where
+    I: FusedIterator<Item = io::Result<O>>,
+    F: FnMut(ErrorKind) -> bool,
+{
+}
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to write every error to an `io::Write`
+pub trait WriteErrs<O, E>: Sized {
+    /// Write the `Display` output of every `Err` item as a line to `writer`, passing all items
+    /// through unchanged
+    ///
+    /// A prefix for every line can be set via [`WriteErrsTo::prefix`]. Failures to write are
+    /// ignored, like `eprintln!` ignores a closed stderr.
+    ///
+    /// ```
+    /// use resiter::io::WriteErrs;
+    /// use std::str::FromStr;
+    ///
+    /// let mut out = Vec::new();
+    /// let sum: usize = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .write_errs_to(&mut out)
+    ///     .prefix("error: ")
+    ///     .filter_map(Result::ok)
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 4);
+    /// assert_eq!(out, b"error: invalid digit found in string\n");
+    /// ```
+    fn write_errs_to<W>(self, writer: W) -> WriteErrsTo<Self, W>
+    where
+        W: io::Write,
+        E: fmt::Display;
+
+    /// Write every `Err` item to `writer` with `f`, passing all items through unchanged
+    ///
+    /// Failures to write are ignored.
+    ///
+    /// ```
+    /// use resiter::io::WriteErrs;
+    /// use std::io::Write;
+    /// use std::str::FromStr;
+    ///
+    /// let mut out = Vec::new();
+    /// let items: Vec<_> = ["1", "a"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .write_errs_with(&mut out, |w, e| writeln!(w, "{:?}", e))
+    ///     .collect();
+    ///
+    /// assert_eq!(items.len(), 2);
+    /// assert_eq!(out, b"ParseIntError { kind: InvalidDigit }\n");
+    /// ```
+    fn write_errs_with<W, F>(self, writer: W, _: F) -> WriteErrsWith<Self, W, F>
+    where
+        W: io::Write,
+        F: FnMut(&mut W, &E) -> io::Result<()>;
+}
+
+impl<I, O, E> WriteErrs<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn write_errs_to<W>(self, writer: W) -> WriteErrsTo<Self, W>
+    where
+        W: io::Write,
+        E: fmt::Display,
+    {
+        WriteErrsTo {
+            iter: self,
+            writer,
+            prefix: String::new(),
+        }
+    }
+
+    #[inline]
+    fn write_errs_with<W, F>(self, writer: W, f: F) -> WriteErrsWith<Self, W, F>
+    where
+        W: io::Write,
+        F: FnMut(&mut W, &E) -> io::Result<()>,
+    {
+        WriteErrsWith {
+            iter: self,
+            writer,
+            f,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct WriteErrsTo<I, W> {
+    iter: I,
+    writer: W,
+    prefix: String,
+}
+
+impl<I, W> WriteErrsTo<I, W> {
+    /// Start every written line with `prefix`
+    #[inline]
+    pub fn prefix<S>(mut self, prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.prefix = prefix.into();
+        self
+    }
+
+    fn write<O, E>(writer: &mut W, prefix: &str, r: &Result<O, E>)
+    where
+        W: io::Write,
+        E: fmt::Display,
+    {
+        if let Err(ref e) = *r {
+            let _ = writeln!(writer, "{}{}", prefix, e);
+        }
+    }
+}
+
+impl<I, W> fmt::Debug for WriteErrsTo<I, W>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WriteErrsTo")
+            .field("iter", &self.iter)
+            .field("prefix", &self.prefix)
+            .finish()
+    }
+}
+
+impl<I, O, E, W> Iterator for WriteErrsTo<I, W>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: fmt::Display,
+    W: io::Write,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        Self::write(&mut self.writer, &self.prefix, &r);
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut writer = self.writer;
+        let prefix = self.prefix;
+        self.iter.fold(init, move |acc, r| {
+            Self::write(&mut writer, &prefix, &r);
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, W> DoubleEndedIterator for WriteErrsTo<I, W>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    E: fmt::Display,
+    W: io::Write,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        Self::write(&mut self.writer, &self.prefix, &r);
+        Some(r)
+    }
+}
+
+impl<I, O, E, W> FusedIterator for WriteErrsTo<I, W>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    E: fmt::Display,
+    W: io::Write,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct WriteErrsWith<I, W, F> {
+    iter: I,
+    writer: W,
+    f: F,
+}
+
+impl<I, W, F> fmt::Debug for WriteErrsWith<I, W, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WriteErrsWith")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, W, F> Iterator for WriteErrsWith<I, W, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    W: io::Write,
+    F: FnMut(&mut W, &E) -> io::Result<()>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        if let Err(ref e) = r {
+            let _ = (self.f)(&mut self.writer, e);
+        }
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut writer = self.writer;
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| {
+            if let Err(ref e) = r {
+                let _ = f(&mut writer, e);
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, W, F> DoubleEndedIterator for WriteErrsWith<I, W, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    W: io::Write,
+    F: FnMut(&mut W, &E) -> io::Result<()>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        if let Err(ref e) = r {
+            let _ = (self.f)(&mut self.writer, e);
+        }
+        Some(r)
+    }
+}
+
+impl<I, O, E, W, F> FusedIterator for WriteErrsWith<I, W, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    W: io::Write,
+    F: FnMut(&mut W, &E) -> io::Result<()>,
+{
+}
+
+/// Extension trait for `io::Lines` to iterate over the successfully read lines
+pub trait LinesExt: Sized {
+    /// Yield all successfully read lines, passing every other error to `f`
+    ///
+    /// `ErrorKind::Interrupted` errors are retried and never reach `f`. Lines which are not
+    /// valid UTF-8 (`ErrorKind::InvalidData`) are passed to `f` and skipped. Any other error
+    /// is passed to `f` and ends the iteration, as a reader which failed for good would
+    /// otherwise keep returning the same error forever. The callback is a good place to hook
+    /// in logging.
+    ///
+    /// ```
+    /// use resiter::io::LinesExt;
+    /// use std::io::{BufRead, Cursor};
+    ///
+    /// let mut errors = 0;
+    /// let lines: Vec<String> = Cursor::new(b"first\nsecond\n\xFF\nthird")
+    ///     .lines()
+    ///     .lines_oks_reporting_errs(|e| {
+    ///         errors += 1;
+    ///         eprintln!("skipping line: {}", e);
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(lines, vec!["first", "second", "third"]);
+    /// assert_eq!(errors, 1);
+    /// ```
+    fn lines_oks_reporting_errs<F>(self, _: F) -> LinesOks<Self, F>
+    where
+        F: FnMut(&io::Error);
+}
+
+impl<B> LinesExt for Lines<B>
+where
+    B: BufRead,
+{
+    #[inline]
+    fn lines_oks_reporting_errs<F>(self, f: F) -> LinesOks<Self, F>
+    where
+        F: FnMut(&io::Error),
+    {
+        LinesOks {
+            iter: self,
+            f,
+            done: false,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct LinesOks<I, F> {
+    iter: I,
+    f: F,
+    /// Set once a fatal error was reported
+    done: bool,
+}
+
+impl<I, F> fmt::Debug for LinesOks<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LinesOks")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for LinesOks<I, F>
+where
+    I: Iterator<Item = io::Result<String>>,
+    F: FnMut(&io::Error),
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            match self.iter.next()? {
+                Ok(line) => return Some(line),
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(ref e) if e.kind() == ErrorKind::InvalidData => (self.f)(e),
+                Err(e) => {
+                    (self.f)(&e);
+                    self.done = true;
+                    return None;
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+}
+
+/// Extension trait for `fs::ReadDir` to iterate over the paths of the directory entries
+pub trait ReadDirExt: Sized {
+    /// Map every directory entry to its full path
+    ///
+    /// The result is again an iterator over `io::Result`, so it can be combined with
+    /// [`IoResults`], e.g. to ignore entries which were removed while iterating.
+    ///
+    /// ```
+    /// use resiter::io::{IoResults, ReadDirExt};
+    /// use std::io::ErrorKind;
+    /// use std::path::Path;
+    ///
+    /// let paths = std::fs::read_dir("src")
+    ///     .unwrap()
+    ///     .read_dir_paths()
+    ///     .ignore_kind(ErrorKind::NotFound)
+    ///     .collect::<std::io::Result<Vec<_>>>()
+    ///     .unwrap();
+    ///
+    /// assert!(paths.iter().any(|p| p == Path::new("src/lib.rs")));
+    /// ```
+    fn read_dir_paths(self) -> ReadDirPaths;
+}
+
+impl ReadDirExt for ReadDir {
+    #[inline]
+    fn read_dir_paths(self) -> ReadDirPaths {
+        ReadDirPaths { iter: self }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Debug)]
+pub struct ReadDirPaths {
+    iter: ReadDir,
+}
+
+impl Iterator for ReadDirPaths {
+    type Item = io::Result<PathBuf>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| r.map(|entry| entry.path()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ignore_kind_fold_and_rev() {
+        let items = || {
+            vec![
+                Ok(1),
+                Err(io::Error::new(ErrorKind::NotFound, "a")),
+                Ok(2),
+                Err(io::Error::new(ErrorKind::InvalidData, "b")),
+            ]
+            .into_iter()
+            .ignore_kind(ErrorKind::NotFound)
+        };
+
+        let kinds = items().fold(Vec::new(), |mut acc, r| {
+            acc.push(r.map_err(|e| e.kind()));
+            acc
+        });
+        assert_eq!(kinds, vec![Ok(1), Ok(2), Err(ErrorKind::InvalidData)]);
+
+        let kinds: Vec<_> = items().rev().map(|r| r.map_err(|e| e.kind())).collect();
+        assert_eq!(kinds, vec![Err(ErrorKind::InvalidData), Ok(2), Ok(1)]);
+    }
+
+    struct FailingReader;
+
+    impl io::Read for FailingReader {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(ErrorKind::Other, "device gone"))
+        }
+    }
+
+    #[test]
+    fn test_lines_oks_stops_at_fatal_error() {
+        let mut errors = 0;
+        let mut lines = io::BufReader::new(FailingReader)
+            .lines()
+            .lines_oks_reporting_errs(|_| errors += 1);
+
+        assert_eq!(lines.next(), None);
+        assert_eq!(lines.next(), None);
+        drop(lines);
+        assert_eq!(errors, 1);
+    }
+}
@@ -21,6 +21,28 @@
 //! If a feature of this crate uses external dependencies, it should be hidden behind a feature
 //! gate. The crate itself should be usable without any dependencies besides `std`!
 //!
+//! The following cargo features are available:
+//!
+//! * `alloc`: Consumers which need an allocator, e.g. collecting into `Vec`s or bundling errors
+//!   into an [`AggregateError`](aggregate/struct.AggregateError.html)
+//! * `std`: Extensions which need the standard library, e.g. boxing `std::error::Error`s or
+//!   the [unique](unique/index.html), [io](io/index.html), [causes](causes/index.html) and
+//!   [unwind](unwind/index.html) modules. Implies `alloc`
+//! * `stream`: Extensions for `futures_core::Stream<Item = Result<O, E>>`, see the
+//!   [stream](stream/index.html) module
+//! * `rayon`: Extensions for rayon's `ParallelIterator<Item = Result<O, E>>`, see the
+//!   [parallel](parallel/index.html) module
+//! * `anyhow`, `eyre`: Attach context to every error, producing `anyhow::Error` or
+//!   `eyre::Report` items, see the [context](context/index.html) module
+//! * `either`: Split `Ok` values via `either::Either` and convert between `Result` and `Either`
+//!   items, see the [either_ext](either_ext/index.html) module
+//! * `test-util`: Assertion helpers for tests of code producing fallible iterators, see the
+//!   [test_util](test_util/index.html) module. Implies `alloc`
+//! * `try_trait`: Extensions for iterators over any `core::ops::Try` type, see the
+//!   [try_trait](try_trait/index.html) module. Requires a nightly compiler
+//! * `tracing`: Emit `tracing` events for errors and enter spans while iterating, see the
+//!   [trace](trace/index.html) module
+//!
 //! # Features
 //!
 //! Features included in this crate:
@@ -177,40 +199,184 @@
 //!
 
 #![cfg_attr(not(test), no_std)]
+#![cfg_attr(feature = "try_trait", feature(try_trait_v2, try_trait_v2_residual))]
+
+#[cfg(test)]
+extern crate core;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "anyhow")]
+extern crate anyhow;
+#[cfg(feature = "either")]
+extern crate either;
+#[cfg(feature = "eyre")]
+extern crate eyre;
+#[cfg(feature = "stream")]
+extern crate futures_core;
+#[cfg(feature = "stream")]
+extern crate pin_project_lite;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+#[cfg(feature = "tracing")]
+extern crate tracing;
 
+#[cfg(feature = "alloc")]
+pub mod aggregate;
 pub mod and_then;
+pub mod and_then_filter;
+pub mod array_chunks;
+pub mod batching;
+pub mod capture;
+#[cfg(feature = "std")]
+pub mod causes;
+#[cfg(feature = "alloc")]
+pub mod collect;
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+pub mod context;
+pub mod convert;
+pub mod count;
+pub mod dedup;
+#[cfg(feature = "either")]
+pub mod either_ext;
 pub mod errors;
 pub mod filter;
 pub mod filter_map;
 pub mod flat_map;
 pub mod flatten;
+pub mod fold;
+pub mod for_each;
+pub mod inspect;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod lift;
 pub mod map;
+pub mod merge;
+pub mod minmax;
 pub mod ok_or_else;
 pub mod oks;
 pub mod onerr;
 pub mod onok;
+pub mod option;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod peek;
+pub mod policy;
+pub mod position;
 pub mod prelude;
+pub mod query;
+pub mod refs;
+#[cfg(feature = "alloc")]
+pub mod report;
+pub mod retry;
+pub mod sink;
+pub mod skip;
+pub mod sources;
+#[cfg(feature = "alloc")]
+pub mod split;
+#[cfg(feature = "stream")]
+pub mod stream;
+pub mod sum;
+pub mod take;
+pub mod tap;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "tracing")]
+pub mod trace;
+pub mod transpose;
 pub mod try_filter;
 pub mod try_filter_map;
 pub mod try_map;
+#[cfg(feature = "try_trait")]
+pub mod try_trait;
+#[cfg(feature = "std")]
+pub mod unique;
+#[cfg(feature = "std")]
+pub mod unwind;
 pub mod unwrap;
+pub mod unzip;
 mod util;
+#[cfg(feature = "alloc")]
+pub mod validated;
 pub mod while_ok;
+pub mod zip;
 
+#[cfg(feature = "alloc")]
+pub use aggregate::{AggregateError, CollectAggregate};
 pub use and_then::AndThen;
+pub use and_then_filter::AndThenFilter;
+pub use array_chunks::ArrayChunks;
+pub use batching::Batching;
+pub use capture::CaptureErrors;
+#[cfg(feature = "std")]
+pub use causes::ErrorCauses;
+#[cfg(feature = "alloc")]
+pub use collect::{CollectMap, CollectResults, Partition};
+#[cfg(feature = "anyhow")]
+pub use context::AnyhowContext;
+#[cfg(feature = "eyre")]
+pub use context::EyreWrapErr;
+pub use convert::Convert;
+pub use count::{CountResults, ResultCounts, ResultSummary};
+pub use dedup::Dedup;
+#[cfg(feature = "either")]
+pub use either_ext::{EitherIntoResults, EitherResults};
 pub use errors::GetErrors;
 pub use filter::Filter;
 pub use filter_map::FilterMap;
 pub use flat_map::FlatMap;
-pub use flatten::Flatten;
+pub use flatten::{Flatten, FlattenNested};
+pub use fold::FoldOk;
+pub use for_each::TryForEach;
+pub use inspect::Inspect;
+#[cfg(feature = "std")]
+pub use io::{IoResults, LinesExt, ReadDirExt, WriteErrs};
+pub use lift::Lift;
 pub use map::Map;
-pub use ok_or_else::{IterInnerOkOrElse, ResultOptionExt};
+pub use merge::Merge;
+pub use minmax::MinMaxOk;
+pub use ok_or_else::{IterInnerOkOrElse, IterInnerOption, ResultOptionExt};
 pub use oks::GetOks;
-pub use onerr::OnErrDo;
+pub use onerr::{OnErrDo, OnFirstErrDo};
 pub use onok::OnOkDo;
+pub use option::{GetSomes, OnNoneDo, OptionAndThen, OptionFilter, OptionMap, OptionOkOrElse};
+#[cfg(feature = "rayon")]
+pub use parallel::ParallelResults;
+pub use peek::Peek;
+pub use policy::{ErrorHandling, ErrorPolicy};
+pub use position::{Positioned, Positions};
+pub use query::Query;
+pub use refs::ResultRefs;
+#[cfg(feature = "alloc")]
+pub use report::ErrorReport;
+pub use retry::Retry;
+pub use sink::ErrorSink;
+pub use skip::Skip;
+#[cfg(feature = "alloc")]
+pub use split::SplitResults;
+pub use sum::SumOks;
+pub use take::Take;
+pub use tap::Tap;
+#[cfg(feature = "test-util")]
+pub use test_util::AssertResults;
+#[cfg(feature = "tracing")]
+pub use trace::TraceResults;
+pub use transpose::{TransposeOptions, TransposeResults};
 pub use try_filter::TryFilter;
 pub use try_filter_map::TryFilterMap;
 pub use try_map::TryMap;
-pub use unwrap::UnwrapWithExt;
+#[cfg(feature = "try_trait")]
+pub use try_trait::TryOutputs;
+#[cfg(feature = "std")]
+pub use unique::Unique;
+#[cfg(feature = "std")]
+pub use unwind::CatchUnwind;
+pub use unwrap::{UnwrapAll, UnwrapWithExt};
+pub use unzip::UnzipOks;
 pub use util::{GetErr, GetOk, Process};
+#[cfg(feature = "alloc")]
+pub use validated::Validated;
 pub use while_ok::WhileOk;
+pub use zip::Zip;
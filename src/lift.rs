@@ -0,0 +1,197 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// Extension trait for any `Iterator<Item = T>` to lift its items into `Result`s
+pub trait Lift<T>: Sized {
+    /// Wrap every item as `Ok(T)`
+    ///
+    /// The error type is a type parameter of the method, so it can be inferred from the rest of
+    /// the pipeline or given explicitly.
+    ///
+    /// ```
+    /// use resiter::lift::Lift;
+    /// use std::num::ParseIntError;
+    /// use std::str::FromStr;
+    ///
+    /// let parsed = ["3", "a"].iter().map(|txt| usize::from_str(txt));
+    ///
+    /// let all: Vec<Result<usize, ParseIntError>> = vec![1, 2].into_iter().into_oks().chain(parsed).collect();
+    ///
+    /// assert_eq!(all[0], Ok(1));
+    /// assert_eq!(all[1], Ok(2));
+    /// assert_eq!(all[2], Ok(3));
+    /// assert!(all[3].is_err());
+    ///
+    /// let explicit: Vec<_> = vec![1, 2].into_iter().into_oks::<String>().collect();
+    /// assert_eq!(explicit, vec![Ok(1), Ok(2)]);
+    /// ```
+    fn into_oks<E>(self) -> IntoOks<Self, E>;
+
+    /// Wrap every item as `Err(T)`
+    ///
+    /// The `Ok` type is a type parameter of the method, so it can be inferred from the rest of
+    /// the pipeline or given explicitly.
+    ///
+    /// ```
+    /// use resiter::lift::Lift;
+    ///
+    /// let v: Vec<Result<usize, &str>> = vec!["a", "b"].into_iter().into_errs().collect();
+    ///
+    /// assert_eq!(v, vec![Err("a"), Err("b")]);
+    /// ```
+    fn into_errs<O>(self) -> IntoErrs<Self, O>;
+}
+
+impl<I, T> Lift<T> for I
+where
+    I: Iterator<Item = T> + Sized,
+{
+    #[inline]
+    fn into_oks<E>(self) -> IntoOks<Self, E> {
+        IntoOks {
+            iter: self,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn into_errs<O>(self) -> IntoErrs<Self, O> {
+        IntoErrs {
+            iter: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct IntoOks<I, E> {
+    iter: I,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<I, E> Clone for IntoOks<I, E>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        IntoOks {
+            iter: self.iter.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, E> fmt::Debug for IntoOks<I, E>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IntoOks").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, E> Iterator for IntoOks<I, E>
+where
+    I: Iterator,
+{
+    type Item = Result<I::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Ok)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, t| g(acc, Ok(t)))
+    }
+}
+
+impl<I, E> DoubleEndedIterator for IntoOks<I, E>
+where
+    I: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Ok)
+    }
+}
+
+impl<I, E> FusedIterator for IntoOks<I, E> where I: FusedIterator {}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct IntoErrs<I, O> {
+    iter: I,
+    _marker: PhantomData<fn() -> O>,
+}
+
+impl<I, O> Clone for IntoErrs<I, O>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        IntoErrs {
+            iter: self.iter.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, O> fmt::Debug for IntoErrs<I, O>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IntoErrs")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O> Iterator for IntoErrs<I, O>
+where
+    I: Iterator,
+{
+    type Item = Result<O, I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Err)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, t| g(acc, Err(t)))
+    }
+}
+
+impl<I, O> DoubleEndedIterator for IntoErrs<I, O>
+where
+    I: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Err)
+    }
+}
+
+impl<I, O> FusedIterator for IntoErrs<I, O> where I: FusedIterator {}
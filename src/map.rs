@@ -4,10 +4,15 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform Oks and Errors.
 pub trait Map<O, E>: Sized {
     /// Map all `Ok` items while leaving `Err` as is
     ///
+    /// The closure is not called for items skipped via `nth`, `count` or `last`.
+    ///
     /// ```
     /// use resiter::map::Map;
     /// use std::str::FromStr;
@@ -30,6 +35,8 @@ pub trait Map<O, E>: Sized {
 
     /// Map all `Err` items while leaving `Ok` as is
     ///
+    /// The closure is not called for items skipped via `nth`, `count` or `last`.
+    ///
     /// ```
     /// use resiter::map::Map;
     /// use std::str::FromStr;
@@ -53,6 +60,147 @@ pub trait Map<O, E>: Sized {
     fn map_err<F, E2>(self, _: F) -> MapErr<Self, F>
     where
         F: FnMut(E) -> E2;
+
+    /// Map `Ok` items while the closure returns `Some`, leaving `Err` as is
+    ///
+    /// The iterator stops as soon as the closure returns `None` for an `Ok` item.
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    /// use std::str::FromStr;
+    ///
+    /// let mapped: Vec<_> = ["1", "2", "a", "4", "5"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .map_while_ok(|i| if i < 4 { Some(2 * i) } else { None })
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped.len(), 3);
+    /// assert_eq!(mapped[0], Ok(2));
+    /// assert_eq!(mapped[1], Ok(4));
+    /// assert!(mapped[2].is_err());
+    /// ```
+    fn map_while_ok<F, O2>(self, _: F) -> MapWhileOk<Self, F>
+    where
+        F: FnMut(O) -> Option<O2>;
+
+    /// Map all `Err` items while leaving `Ok` as is, passing the zero-based position of the item
+    ///
+    /// The position counts all items of the underlying iterator, not only the errors.
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    /// use std::str::FromStr;
+    ///
+    /// let mapped: Vec<_> = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .map_err_with_index(|idx, e| format!("line {}: {}", idx + 1, e))
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped[2], Err("line 3: invalid digit found in string".to_string()));
+    /// assert_eq!(mapped[4], Err("line 5: invalid digit found in string".to_string()));
+    /// ```
+    fn map_err_with_index<F, E2>(self, _: F) -> MapErrWithIndex<Self, F>
+    where
+        F: FnMut(usize, E) -> E2;
+
+    /// Map all `Ok` items while leaving `Err` as is, passing the zero-based position of the item
+    ///
+    /// The position counts all items of the underlying iterator, not only the `Ok` values.
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    /// use std::str::FromStr;
+    ///
+    /// let mapped: Vec<_> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .map_ok_with_index(|idx, i| (idx, 2 * i))
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped[0], Ok((0, 2)));
+    /// assert!(mapped[1].is_err());
+    /// assert_eq!(mapped[2], Ok((2, 6)));
+    /// ```
+    fn map_ok_with_index<F, O2>(self, _: F) -> MapOkWithIndex<Self, F>
+    where
+        F: FnMut(usize, O) -> O2;
+
+    /// Map all `Ok` items with `f_ok` and all `Err` items with `f_err` in a single adapter
+    ///
+    /// This is equivalent to `map_ok(f_ok).map_err(f_err)`, without nesting two adapters. The
+    /// closures are not called for items skipped via `nth`, `count` or `last`.
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    /// use std::str::FromStr;
+    ///
+    /// let mapped: Vec<_> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .map_both(|i| 2 * i, |e| e.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     mapped,
+    ///     vec![
+    ///         Ok(2),
+    ///         Err("invalid digit found in string".to_string()),
+    ///         Ok(6),
+    ///     ]
+    /// );
+    /// ```
+    fn map_both<F, G, O2, E2>(self, f_ok: F, f_err: G) -> MapBoth<Self, F, G>
+    where
+        F: FnMut(O) -> O2,
+        G: FnMut(E) -> E2;
+
+    /// Swap `Ok` and `Err`, turning every `Result<O, E>` into a `Result<E, O>`
+    ///
+    /// This makes all `Ok`-side adapters of this crate usable on the errors.
+    ///
+    /// ```
+    /// use resiter::filter::Filter;
+    /// use resiter::map::Map;
+    ///
+    /// let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3)];
+    ///
+    /// let swapped: Vec<_> = v
+    ///     .into_iter()
+    ///     .swap_ok_err()
+    ///     .filter_ok(|e| *e > 2)
+    ///     .swap_ok_err()
+    ///     .collect();
+    ///
+    /// assert_eq!(swapped, vec![Ok(1), Err(3)]);
+    /// ```
+    fn swap_ok_err(self) -> SwapOkErr<Self>;
+
+    /// Collapse every item into a single value, using `f_ok` for `Ok` and `f_err` for `Err`
+    ///
+    /// This is the lazy counterpart of `Result::map_or_else` and yields plain `T` values. The
+    /// closures are not called for items skipped via `nth`, `count` or `last`.
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    /// use std::str::FromStr;
+    ///
+    /// let lines: Vec<String> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .map_ok_or_else(|i| format!("ok: {}", i), |e| format!("error: {}", e))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     lines,
+    ///     vec!["ok: 1", "error: invalid digit found in string", "ok: 3"]
+    /// );
+    /// ```
+    fn map_ok_or_else<F, G, T>(self, f_ok: F, f_err: G) -> MapOkOrElse<Self, F, G>
+    where
+        F: FnMut(O) -> T,
+        G: FnMut(E) -> T;
 }
 
 impl<I, O, E> Map<O, E> for I
@@ -74,14 +222,91 @@ where
     {
         MapErr { iter: self, f }
     }
+
+    #[inline]
+    fn map_while_ok<F, O2>(self, f: F) -> MapWhileOk<Self, F>
+    where
+        F: FnMut(O) -> Option<O2>,
+    {
+        MapWhileOk {
+            iter: self,
+            f,
+            done: false,
+        }
+    }
+
+    #[inline]
+    fn map_ok_with_index<F, O2>(self, f: F) -> MapOkWithIndex<Self, F>
+    where
+        F: FnMut(usize, O) -> O2,
+    {
+        MapOkWithIndex {
+            iter: self,
+            f,
+            index: 0,
+        }
+    }
+
+    #[inline]
+    fn map_err_with_index<F, E2>(self, f: F) -> MapErrWithIndex<Self, F>
+    where
+        F: FnMut(usize, E) -> E2,
+    {
+        MapErrWithIndex {
+            iter: self,
+            f,
+            index: 0,
+        }
+    }
+
+    #[inline]
+    fn map_both<F, G, O2, E2>(self, f_ok: F, f_err: G) -> MapBoth<Self, F, G>
+    where
+        F: FnMut(O) -> O2,
+        G: FnMut(E) -> E2,
+    {
+        MapBoth {
+            iter: self,
+            f_ok,
+            f_err,
+        }
+    }
+
+    #[inline]
+    fn swap_ok_err(self) -> SwapOkErr<Self> {
+        SwapOkErr { iter: self }
+    }
+
+    #[inline]
+    fn map_ok_or_else<F, G, T>(self, f_ok: F, f_err: G) -> MapOkOrElse<Self, F, G>
+    where
+        F: FnMut(O) -> T,
+        G: FnMut(E) -> T,
+    {
+        MapOkOrElse {
+            iter: self,
+            f_ok,
+            f_err,
+        }
+    }
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct MapOk<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for MapOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapOk").field("iter", &self.iter).finish()
+    }
+}
+
 impl<I, O, E, F, O2> Iterator for MapOk<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -97,14 +322,73 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| g(acc, r.map(&mut f)))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|r| r.map(&mut self.f))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        let f = self.f;
+        self.iter.last().map(|r| r.map(f))
+    }
+}
+
+impl<I, O, E, F, O2> DoubleEndedIterator for MapOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|r| r.map(&mut self.f))
+    }
+}
+
+impl<I, O, E, F, O2> FusedIterator for MapOk<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+{
+}
+
+impl<I, O, E, F, O2> ExactSizeIterator for MapOk<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+{
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct MapErr<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for MapErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapErr").field("iter", &self.iter).finish()
+    }
+}
+
 impl<I, O, E, F, E2> Iterator for MapErr<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -120,12 +404,577 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, r| g(acc, r.map_err(&mut f)))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|r| r.map_err(&mut self.f))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        let f = self.f;
+        self.iter.last().map(|r| r.map_err(f))
+    }
+}
+
+impl<I, O, E, F, E2> DoubleEndedIterator for MapErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> E2,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|r| r.map_err(&mut self.f))
+    }
+}
+
+impl<I, O, E, F, E2> FusedIterator for MapErr<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> E2,
+{
+}
+
+impl<I, O, E, F, E2> ExactSizeIterator for MapErr<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> E2,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapWhileOk<I, F> {
+    iter: I,
+    f: F,
+    done: bool,
+}
+
+impl<I, F> fmt::Debug for MapWhileOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapWhileOk")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, O2> Iterator for MapWhileOk<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Option<O2>,
+{
+    type Item = Result<O2, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.iter.next() {
+            Some(Ok(x)) => match (self.f)(x) {
+                Some(x) => Some(Ok(x)),
+                None => {
+                    self.done = true;
+                    None
+                }
+            },
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, O, E, F, O2> FusedIterator for MapWhileOk<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Option<O2>,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapErrWithIndex<I, F> {
+    iter: I,
+    f: F,
+    index: usize,
+}
+
+impl<I, F> fmt::Debug for MapErrWithIndex<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapErrWithIndex")
+            .field("iter", &self.iter)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, E2> Iterator for MapErrWithIndex<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(usize, E) -> E2,
+{
+    type Item = Result<O, E2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        let f = &mut self.f;
+        Some(r.map_err(|x| f(index, x)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut index = self.index;
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| {
+            let i = index;
+            index += 1;
+            g(acc, r.map_err(|x| f(i, x)))
+        })
+    }
+}
+
+impl<I, O, E, F, E2> DoubleEndedIterator for MapErrWithIndex<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>> + ExactSizeIterator,
+    F: FnMut(usize, E) -> E2,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        let index = self.index + self.iter.len();
+        let f = &mut self.f;
+        Some(r.map_err(|x| f(index, x)))
+    }
+}
+
+impl<I, O, E, F, E2> FusedIterator for MapErrWithIndex<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(usize, E) -> E2,
+{
+}
+
+impl<I, O, E, F, E2> ExactSizeIterator for MapErrWithIndex<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(usize, E) -> E2,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapOkWithIndex<I, F> {
+    iter: I,
+    f: F,
+    index: usize,
+}
+
+impl<I, F> fmt::Debug for MapOkWithIndex<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapOkWithIndex")
+            .field("iter", &self.iter)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, O2> Iterator for MapOkWithIndex<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(usize, O) -> O2,
+{
+    type Item = Result<O2, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        let f = &mut self.f;
+        Some(r.map(|x| f(index, x)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut index = self.index;
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| {
+            let i = index;
+            index += 1;
+            g(acc, r.map(|x| f(i, x)))
+        })
+    }
+}
+
+impl<I, O, E, F, O2> DoubleEndedIterator for MapOkWithIndex<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>> + ExactSizeIterator,
+    F: FnMut(usize, O) -> O2,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        let index = self.index + self.iter.len();
+        let f = &mut self.f;
+        Some(r.map(|x| f(index, x)))
+    }
+}
+
+impl<I, O, E, F, O2> FusedIterator for MapOkWithIndex<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(usize, O) -> O2,
+{
+}
+
+impl<I, O, E, F, O2> ExactSizeIterator for MapOkWithIndex<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(usize, O) -> O2,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapBoth<I, F, G> {
+    iter: I,
+    f_ok: F,
+    f_err: G,
+}
+
+impl<I, F, G> fmt::Debug for MapBoth<I, F, G>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapBoth").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, O, E, F, G, O2, E2> Iterator for MapBoth<I, F, G>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+    G: FnMut(E) -> E2,
+{
+    type Item = Result<O2, E2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|r| r.map(&mut self.f_ok).map_err(&mut self.f_err))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, H>(self, init: Acc, mut h: H) -> Acc
+    where
+        H: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f_ok = self.f_ok;
+        let mut f_err = self.f_err;
+        self.iter.fold(init, move |acc, r| {
+            h(acc, r.map(&mut f_ok).map_err(&mut f_err))
+        })
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth(n)
+            .map(|r| r.map(&mut self.f_ok).map_err(&mut self.f_err))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        let (f_ok, f_err) = (self.f_ok, self.f_err);
+        self.iter.last().map(|r| r.map(f_ok).map_err(f_err))
+    }
+}
+
+impl<I, O, E, F, G, O2, E2> DoubleEndedIterator for MapBoth<I, F, G>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+    G: FnMut(E) -> E2,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|r| r.map(&mut self.f_ok).map_err(&mut self.f_err))
+    }
+}
+
+impl<I, O, E, F, G, O2, E2> FusedIterator for MapBoth<I, F, G>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+    G: FnMut(E) -> E2,
+{
+}
+
+impl<I, O, E, F, G, O2, E2> ExactSizeIterator for MapBoth<I, F, G>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+    G: FnMut(E) -> E2,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct SwapOkErr<I> {
+    iter: I,
+}
+
+#[inline]
+fn swap<O, E>(r: Result<O, E>) -> Result<E, O> {
+    match r {
+        Ok(o) => Err(o),
+        Err(e) => Ok(e),
+    }
+}
+
+impl<I, O, E> Iterator for SwapOkErr<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<E, O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(swap)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, r| g(acc, swap(r)))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(swap)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        self.iter.last().map(swap)
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for SwapOkErr<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(swap)
+    }
+}
+
+impl<I, O, E> FusedIterator for SwapOkErr<I> where I: FusedIterator<Item = Result<O, E>> {}
+
+impl<I, O, E> ExactSizeIterator for SwapOkErr<I> where I: ExactSizeIterator<Item = Result<O, E>> {}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapOkOrElse<I, F, G> {
+    iter: I,
+    f_ok: F,
+    f_err: G,
+}
+
+impl<I, F, G> fmt::Debug for MapOkOrElse<I, F, G>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapOkOrElse")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, G, T> Iterator for MapOkOrElse<I, F, G>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(O) -> T,
+    G: FnMut(E) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|r| r.map_or_else(&mut self.f_err, &mut self.f_ok))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, H>(self, init: Acc, mut h: H) -> Acc
+    where
+        H: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f_ok = self.f_ok;
+        let mut f_err = self.f_err;
+        self.iter.fold(init, move |acc, r| {
+            h(acc, r.map_or_else(&mut f_err, &mut f_ok))
+        })
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter
+            .nth(n)
+            .map(|r| r.map_or_else(&mut self.f_err, &mut self.f_ok))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        let (f_ok, f_err) = (self.f_ok, self.f_err);
+        self.iter.last().map(|r| r.map_or_else(f_err, f_ok))
+    }
+}
+
+impl<I, O, E, F, G, T> DoubleEndedIterator for MapOkOrElse<I, F, G>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> T,
+    G: FnMut(E) -> T,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|r| r.map_or_else(&mut self.f_err, &mut self.f_ok))
+    }
+}
+
+impl<I, O, E, F, G, T> FusedIterator for MapOkOrElse<I, F, G>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> T,
+    G: FnMut(E) -> T,
+{
+}
+
+impl<I, O, E, F, G, T> ExactSizeIterator for MapOkOrElse<I, F, G>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> T,
+    G: FnMut(E) -> T,
+{
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_map_ok_nth_skips_closure() {
+        let mut calls = 0;
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(2), Err(3), Ok(4)];
+        let mut iter = v.into_iter().map_ok(|i| {
+            calls += 1;
+            i * 2
+        });
+
+        assert_eq!(iter.nth(1), Some(Ok(4)));
+        assert_eq!(iter.count(), 2);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_exact_size() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mut iter = v
+            .into_iter()
+            .map_ok(|i| i + 1)
+            .map_err(|e| e + 1)
+            .map_both(|i| i, |e| e)
+            .swap_ok_err();
+
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+    }
+
     #[test]
     fn test_map_ok_hint() {
         use std::str::FromStr;
@@ -151,4 +1000,77 @@ mod tests {
 
         assert_eq!(hint, (5, Some(5)));
     }
+
+    #[test]
+    fn test_map_while_ok_hint() {
+        use std::str::FromStr;
+
+        let hint = ["1", "2", "a", "4", "5"]
+            .iter()
+            .map(|txt| usize::from_str(txt))
+            .map_while_ok(|i| Some(2 * i))
+            .size_hint();
+
+        assert_eq!(hint, (0, Some(5)));
+    }
+
+    #[test]
+    fn test_map_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mapped: Vec<_> = v.into_iter().map_ok(|i| 2 * i).rev().collect();
+
+        assert_eq!(mapped, vec![Ok(6), Err(2), Ok(2)]);
+    }
+
+    #[test]
+    fn test_map_err_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mapped: Vec<_> = v.into_iter().map_err(|i| 2 * i).rev().collect();
+
+        assert_eq!(mapped, vec![Ok(3), Err(4), Ok(1)]);
+    }
+
+    #[test]
+    fn test_map_is_fused() {
+        fn assert_fused<I: FusedIterator>(_: I) {}
+
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2)];
+        assert_fused(v.iter().cloned().map_ok(|i| 2 * i));
+        assert_fused(v.iter().cloned().map_err(|i| 2 * i));
+        assert_fused(v.into_iter().map_while_ok(Some));
+    }
+
+    #[test]
+    fn test_map_ok_clone_and_debug() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mut iter = v.into_iter().map_ok(|i| 2 * i);
+        assert_eq!(iter.next(), Some(Ok(2)));
+
+        let cloned: Vec<_> = iter.clone().collect();
+        assert_eq!(cloned, vec![Err(2), Ok(6)]);
+        assert_eq!(iter.count(), 2);
+
+        let debug = format!("{:?}", [Ok::<u8, u8>(1)].iter().cloned().map_ok(|i| i));
+        assert!(debug.starts_with("MapOk { iter: "));
+    }
+
+    #[test]
+    fn test_map_err_with_index_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Err(4)];
+        let mut iter = v.into_iter().map_err_with_index(|idx, x| (idx, x));
+        assert_eq!(iter.next().map(|r| r.is_ok()), Some(true));
+
+        let rest: Vec<_> = iter.rev().filter_map(|r| r.err()).collect();
+        assert_eq!(rest, vec![(3, 4), (1, 2)]);
+    }
+
+    #[test]
+    fn test_map_ok_with_index_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Err(4)];
+        let mut iter = v.into_iter().map_ok_with_index(|idx, x| (idx, x));
+        assert_eq!(iter.next().map(|r| r.is_ok()), Some(true));
+
+        let rest: Vec<_> = iter.rev().filter_map(|r| r.ok()).collect();
+        assert_eq!(rest, vec![(2, 3)]);
+    }
 }
@@ -0,0 +1,165 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to merge two sorted iterators
+pub trait Merge<O, E>: Sized {
+    /// Merge the sorted `Ok` values of two iterators into one sorted iterator, using the
+    /// comparator `cmp`
+    ///
+    /// Errors of either side are forwarded as soon as they are encountered. If the comparator
+    /// considers two values equal, the one of `self` is yielded first.
+    ///
+    /// ```
+    /// use resiter::merge::Merge;
+    /// use std::str::FromStr;
+    ///
+    /// let a = ["1", "4", "7"].iter().map(|txt| usize::from_str(txt));
+    /// let b = ["2", "x", "5"].iter().map(|txt| usize::from_str(txt));
+    ///
+    /// let merged: Vec<_> = a.merge_ok_by(b, |x, y| x.cmp(y)).collect();
+    ///
+    /// assert_eq!(merged.len(), 6);
+    /// assert_eq!(merged[0], Ok(1));
+    /// assert_eq!(merged[1], Ok(2));
+    /// assert!(merged[2].is_err());
+    /// assert_eq!(merged[3], Ok(4));
+    /// assert_eq!(merged[4], Ok(5));
+    /// assert_eq!(merged[5], Ok(7));
+    /// ```
+    fn merge_ok_by<U, F>(self, other: U, cmp: F) -> MergeOkBy<Self, U::IntoIter, O, F>
+    where
+        U: IntoIterator<Item = Result<O, E>>,
+        F: FnMut(&O, &O) -> Ordering;
+}
+
+impl<I, O, E> Merge<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn merge_ok_by<U, F>(self, other: U, cmp: F) -> MergeOkBy<Self, U::IntoIter, O, F>
+    where
+        U: IntoIterator<Item = Result<O, E>>,
+        F: FnMut(&O, &O) -> Ordering,
+    {
+        MergeOkBy {
+            a: self.fuse(),
+            b: other.into_iter().fuse(),
+            a_next: None,
+            b_next: None,
+            cmp,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MergeOkBy<A, B, O, F> {
+    a: Fuse<A>,
+    b: Fuse<B>,
+    /// The next `Ok` value of `a`, waiting to be compared
+    a_next: Option<O>,
+    /// The next `Ok` value of `b`, waiting to be compared
+    b_next: Option<O>,
+    cmp: F,
+}
+
+impl<A, B, O, F> fmt::Debug for MergeOkBy<A, B, O, F>
+where
+    A: fmt::Debug,
+    B: fmt::Debug,
+    O: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MergeOkBy")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("a_next", &self.a_next)
+            .field("b_next", &self.b_next)
+            .finish()
+    }
+}
+
+impl<A, B, O, E, F> Iterator for MergeOkBy<A, B, O, F>
+where
+    A: Iterator<Item = Result<O, E>>,
+    B: Iterator<Item = Result<O, E>>,
+    F: FnMut(&O, &O) -> Ordering,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.a_next.is_none() {
+            match self.a.next() {
+                Some(Ok(o)) => self.a_next = Some(o),
+                Some(Err(e)) => return Some(Err(e)),
+                None => {}
+            }
+        }
+        if self.b_next.is_none() {
+            match self.b.next() {
+                Some(Ok(o)) => self.b_next = Some(o),
+                Some(Err(e)) => return Some(Err(e)),
+                None => {}
+            }
+        }
+
+        let take_a = match (&self.a_next, &self.b_next) {
+            (Some(a), Some(b)) => (self.cmp)(a, b) != Ordering::Greater,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return None,
+        };
+        if take_a {
+            self.a_next.take().map(Ok)
+        } else {
+            self.b_next.take().map(Ok)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every item of either side is yielded exactly once
+        let buffered = self.a_next.is_some() as usize + self.b_next.is_some() as usize;
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        let lo = a_lo.saturating_add(b_lo).saturating_add(buffered);
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => a.checked_add(b).and_then(|x| x.checked_add(buffered)),
+            _ => None,
+        };
+        (lo, hi)
+    }
+}
+
+impl<A, B, O, E, F> FusedIterator for MergeOkBy<A, B, O, F>
+where
+    A: Iterator<Item = Result<O, E>>,
+    B: Iterator<Item = Result<O, E>>,
+    F: FnMut(&O, &O) -> Ordering,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_ok_by_prefers_self_on_ties() {
+        let a: Vec<Result<(usize, char), ()>> = vec![Ok((1, 'a')), Ok((2, 'a'))];
+        let b: Vec<Result<(usize, char), ()>> = vec![Ok((1, 'b')), Ok((3, 'b'))];
+        let merged: Vec<_> = a.into_iter().merge_ok_by(b, |x, y| x.0.cmp(&y.0)).collect();
+
+        assert_eq!(
+            merged,
+            vec![Ok((1, 'a')), Ok((1, 'b')), Ok((2, 'a')), Ok((3, 'b'))]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::cmp::Ordering;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to find the minimum or maximum `Ok` value
+///
+/// All consumers stop at the first `Err` and return it. Ties are resolved like
+/// `Iterator::min`/`Iterator::max`: the first minimum and the last maximum is returned.
+pub trait MinMaxOk<O, E>: Sized {
+    /// Return the minimum `Ok` value, or `None` if there is none
+    ///
+    /// ```
+    /// use resiter::minmax::MinMaxOk;
+    /// use std::str::FromStr;
+    ///
+    /// let min = ["3", "1", "2"].iter().map(|txt| usize::from_str(txt)).min_ok();
+    /// assert_eq!(min, Ok(Some(1)));
+    ///
+    /// let min = ["3", "a", "1"].iter().map(|txt| usize::from_str(txt)).min_ok();
+    /// assert!(min.is_err());
+    /// ```
+    fn min_ok(self) -> Result<Option<O>, E>
+    where
+        O: Ord;
+
+    /// Return the maximum `Ok` value, or `None` if there is none
+    ///
+    /// ```
+    /// use resiter::minmax::MinMaxOk;
+    /// use std::str::FromStr;
+    ///
+    /// let max = ["3", "1", "2"].iter().map(|txt| usize::from_str(txt)).max_ok();
+    /// assert_eq!(max, Ok(Some(3)));
+    ///
+    /// let max = std::iter::empty::<Result<usize, ()>>().max_ok();
+    /// assert_eq!(max, Ok(None));
+    /// ```
+    fn max_ok(self) -> Result<Option<O>, E>
+    where
+        O: Ord;
+
+    /// Return the minimum `Ok` value with respect to the comparison function
+    ///
+    /// ```
+    /// use resiter::minmax::MinMaxOk;
+    ///
+    /// let min = vec![Ok::<_, ()>(2.5), Ok(-1.0), Ok(0.5)]
+    ///     .into_iter()
+    ///     .min_ok_by(|a, b| a.partial_cmp(b).unwrap());
+    ///
+    /// assert_eq!(min, Ok(Some(-1.0)));
+    /// ```
+    fn min_ok_by<F>(self, _: F) -> Result<Option<O>, E>
+    where
+        F: FnMut(&O, &O) -> Ordering;
+
+    /// Return the maximum `Ok` value with respect to the comparison function
+    ///
+    /// ```
+    /// use resiter::minmax::MinMaxOk;
+    ///
+    /// let max = vec![Ok::<_, ()>(2.5), Ok(-1.0), Ok(0.5)]
+    ///     .into_iter()
+    ///     .max_ok_by(|a, b| a.partial_cmp(b).unwrap());
+    ///
+    /// assert_eq!(max, Ok(Some(2.5)));
+    /// ```
+    fn max_ok_by<F>(self, _: F) -> Result<Option<O>, E>
+    where
+        F: FnMut(&O, &O) -> Ordering;
+
+    /// Return the `Ok` value which gives the minimum key
+    ///
+    /// ```
+    /// use resiter::minmax::MinMaxOk;
+    ///
+    /// let min = vec![Ok::<_, ()>("ccc"), Ok("a"), Ok("bb")]
+    ///     .into_iter()
+    ///     .min_ok_by_key(|s| s.len());
+    ///
+    /// assert_eq!(min, Ok(Some("a")));
+    /// ```
+    fn min_ok_by_key<K, F>(self, _: F) -> Result<Option<O>, E>
+    where
+        K: Ord,
+        F: FnMut(&O) -> K;
+
+    /// Return the `Ok` value which gives the maximum key
+    ///
+    /// ```
+    /// use resiter::minmax::MinMaxOk;
+    ///
+    /// let max = vec![Ok::<_, ()>("ccc"), Ok("a"), Ok("bb")]
+    ///     .into_iter()
+    ///     .max_ok_by_key(|s| s.len());
+    ///
+    /// assert_eq!(max, Ok(Some("ccc")));
+    /// ```
+    fn max_ok_by_key<K, F>(self, _: F) -> Result<Option<O>, E>
+    where
+        K: Ord,
+        F: FnMut(&O) -> K;
+}
+
+/// Select one `Ok` value, `keep_new` decides on the ordering of the current and the new value
+#[inline]
+fn select_by<I, O, E, F, S>(iter: I, mut compare: F, keep_new: S) -> Result<Option<O>, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&O, &O) -> Ordering,
+    S: Fn(Ordering) -> bool,
+{
+    let mut best = None;
+    for res in iter {
+        let o = res?;
+        best = match best {
+            Some(b) if !keep_new(compare(&b, &o)) => Some(b),
+            _ => Some(o),
+        };
+    }
+    Ok(best)
+}
+
+#[inline]
+fn select_by_key<I, O, E, K, F, S>(iter: I, mut f: F, keep_new: S) -> Result<Option<O>, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+    K: Ord,
+    F: FnMut(&O) -> K,
+    S: Fn(Ordering) -> bool,
+{
+    let keyed = iter.map(|res| res.map(|o| (f(&o), o)));
+    select_by(keyed, |a, b| a.0.cmp(&b.0), keep_new).map(|best| best.map(|(_, o)| o))
+}
+
+#[inline]
+fn new_is_less(ord: Ordering) -> bool {
+    ord == Ordering::Greater
+}
+
+#[inline]
+fn new_is_not_less(ord: Ordering) -> bool {
+    ord != Ordering::Greater
+}
+
+impl<I, O, E> MinMaxOk<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn min_ok(self) -> Result<Option<O>, E>
+    where
+        O: Ord,
+    {
+        self.min_ok_by(Ord::cmp)
+    }
+
+    #[inline]
+    fn max_ok(self) -> Result<Option<O>, E>
+    where
+        O: Ord,
+    {
+        self.max_ok_by(Ord::cmp)
+    }
+
+    #[inline]
+    fn min_ok_by<F>(self, compare: F) -> Result<Option<O>, E>
+    where
+        F: FnMut(&O, &O) -> Ordering,
+    {
+        select_by(self, compare, new_is_less)
+    }
+
+    #[inline]
+    fn max_ok_by<F>(self, compare: F) -> Result<Option<O>, E>
+    where
+        F: FnMut(&O, &O) -> Ordering,
+    {
+        select_by(self, compare, new_is_not_less)
+    }
+
+    #[inline]
+    fn min_ok_by_key<K, F>(self, f: F) -> Result<Option<O>, E>
+    where
+        K: Ord,
+        F: FnMut(&O) -> K,
+    {
+        select_by_key(self, f, new_is_less)
+    }
+
+    #[inline]
+    fn max_ok_by_key<K, F>(self, f: F) -> Result<Option<O>, E>
+    where
+        K: Ord,
+        F: FnMut(&O) -> K,
+    {
+        select_by_key(self, f, new_is_not_less)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_max_ok_ties() {
+        let v: Vec<Result<(usize, char), ()>> = vec![Ok((1, 'a')), Ok((0, 'b')), Ok((1, 'c'))];
+
+        let max = v.clone().into_iter().max_ok_by_key(|x| x.0);
+        assert_eq!(max, Ok(Some((1, 'c'))));
+
+        let min = v.into_iter().min_ok_by_key(|x| x.0 / 2);
+        assert_eq!(min, Ok(Some((1, 'a'))));
+    }
+}
@@ -4,6 +4,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension trait for doing `Result<Option<T>, E>`  ->  `Result<T, E>`
 pub trait ResultOptionExt<T, E, F>
 where
@@ -88,6 +91,32 @@ where
     E: Sized,
     F: Fn() -> E;
 
+impl<I, T, E, F> Clone for IterInnerOkOrElseImpl<I, T, E, F>
+where
+    I: Iterator<Item = Result<Option<T>, E>> + Sized + Clone,
+    T: Sized,
+    E: Sized,
+    F: Fn() -> E + Clone,
+{
+    fn clone(&self) -> Self {
+        IterInnerOkOrElseImpl(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<I, T, E, F> fmt::Debug for IterInnerOkOrElseImpl<I, T, E, F>
+where
+    I: Iterator<Item = Result<Option<T>, E>> + Sized + fmt::Debug,
+    T: Sized,
+    E: Sized,
+    F: Fn() -> E,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("IterInnerOkOrElseImpl")
+            .field(&self.0)
+            .finish()
+    }
+}
+
 impl<I, T, E, F> IterInnerOkOrElse<T, E, F> for I
 where
     I: Iterator<Item = Result<Option<T>, E>> + Sized,
@@ -113,4 +142,331 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|e| e.inner_ok_or_else(|| self.1()))
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let f = self.1;
+        self.0
+            .fold(init, move |acc, r| g(acc, r.inner_ok_or_else(&f)))
+    }
+}
+
+impl<I, T, E, F> DoubleEndedIterator for IterInnerOkOrElseImpl<I, T, E, F>
+where
+    I: DoubleEndedIterator<Item = Result<Option<T>, E>> + Sized,
+    T: Sized,
+    E: Sized,
+    F: Fn() -> E,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|e| e.inner_ok_or_else(|| self.1()))
+    }
+}
+
+impl<I, T, E, F> FusedIterator for IterInnerOkOrElseImpl<I, T, E, F>
+where
+    I: FusedIterator<Item = Result<Option<T>, E>> + Sized,
+    T: Sized,
+    E: Sized,
+    F: Fn() -> E,
+{
+}
+
+impl<I, T, E, F> ExactSizeIterator for IterInnerOkOrElseImpl<I, T, E, F>
+where
+    I: ExactSizeIterator<Item = Result<Option<T>, E>> + Sized,
+    T: Sized,
+    E: Sized,
+    F: Fn() -> E,
+{
+}
+
+/// Extension trait for dealing with the inner `Option` of
+/// `Iterator<Item = Result<Option<T>, E>>` without failing
+pub trait IterInnerOption<T, E>: Sized {
+    /// Drop all `Ok(None)` items, unwrapping the `Ok(Some(_))` ones
+    ///
+    /// ```
+    /// use resiter::ok_or_else::IterInnerOption;
+    ///
+    /// let v: Vec<Result<Option<i32>, &'static str>> =
+    ///     vec![Ok(Some(1)), Ok(None), Err("untouched err"), Ok(Some(4))];
+    ///
+    /// let res: Vec<Result<i32, &'static str>> = v.into_iter().filter_inner_none().collect();
+    ///
+    /// assert_eq!(res, vec![Ok(1), Err("untouched err"), Ok(4)]);
+    /// ```
+    fn filter_inner_none(self) -> FilterInnerNone<Self>;
+
+    /// Replace the inner `None` of `Ok(None)` items with a clone of `default`
+    ///
+    /// ```
+    /// use resiter::ok_or_else::IterInnerOption;
+    ///
+    /// let v: Vec<Result<Option<i32>, &'static str>> =
+    ///     vec![Ok(Some(1)), Ok(None), Err("untouched err")];
+    ///
+    /// let res: Vec<Result<i32, &'static str>> = v.into_iter().inner_unwrap_or(0).collect();
+    ///
+    /// assert_eq!(res, vec![Ok(1), Ok(0), Err("untouched err")]);
+    /// ```
+    fn inner_unwrap_or(self, default: T) -> InnerUnwrapOr<Self, T>
+    where
+        T: Clone;
+
+    /// Replace the inner `None` of `Ok(None)` items with `T::default()`
+    ///
+    /// ```
+    /// use resiter::ok_or_else::IterInnerOption;
+    ///
+    /// let v: Vec<Result<Option<String>, &'static str>> =
+    ///     vec![Ok(Some("a".to_string())), Ok(None), Err("untouched err")];
+    ///
+    /// let res: Vec<Result<String, &'static str>> =
+    ///     v.into_iter().inner_unwrap_or_default().collect();
+    ///
+    /// assert_eq!(
+    ///     res,
+    ///     vec![Ok("a".to_string()), Ok(String::new()), Err("untouched err")]
+    /// );
+    /// ```
+    fn inner_unwrap_or_default(self) -> InnerUnwrapOrDefault<Self>
+    where
+        T: Default;
+}
+
+impl<I, T, E> IterInnerOption<T, E> for I
+where
+    I: Iterator<Item = Result<Option<T>, E>> + Sized,
+{
+    #[inline]
+    fn filter_inner_none(self) -> FilterInnerNone<Self> {
+        FilterInnerNone { iter: self }
+    }
+
+    #[inline]
+    fn inner_unwrap_or(self, default: T) -> InnerUnwrapOr<Self, T>
+    where
+        T: Clone,
+    {
+        InnerUnwrapOr {
+            iter: self,
+            default,
+        }
+    }
+
+    #[inline]
+    fn inner_unwrap_or_default(self) -> InnerUnwrapOrDefault<Self>
+    where
+        T: Default,
+    {
+        InnerUnwrapOrDefault { iter: self }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct FilterInnerNone<I> {
+    iter: I,
+}
+
+impl<I, T, E> Iterator for FilterInnerNone<I>
+where
+    I: Iterator<Item = Result<Option<T>, E>>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(Some(t)) => return Some(Ok(t)),
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(Some(t)) => g(acc, Ok(t)),
+            Ok(None) => acc,
+            Err(e) => g(acc, Err(e)),
+        })
+    }
+}
+
+impl<I, T, E> DoubleEndedIterator for FilterInnerNone<I>
+where
+    I: DoubleEndedIterator<Item = Result<Option<T>, E>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back()? {
+                Ok(Some(t)) => return Some(Ok(t)),
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<I, T, E> FusedIterator for FilterInnerNone<I> where
+    I: FusedIterator<Item = Result<Option<T>, E>>
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct InnerUnwrapOr<I, T> {
+    iter: I,
+    default: T,
+}
+
+impl<I, T, E> Iterator for InnerUnwrapOr<I, T>
+where
+    I: Iterator<Item = Result<Option<T>, E>>,
+    T: Clone,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let default = &self.default;
+        self.iter
+            .next()
+            .map(|r| r.map(|o| o.unwrap_or_else(|| default.clone())))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let default = self.default;
+        self.iter.fold(init, move |acc, r| {
+            g(acc, r.map(|o| o.unwrap_or_else(|| default.clone())))
+        })
+    }
+}
+
+impl<I, T, E> DoubleEndedIterator for InnerUnwrapOr<I, T>
+where
+    I: DoubleEndedIterator<Item = Result<Option<T>, E>>,
+    T: Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let default = &self.default;
+        self.iter
+            .next_back()
+            .map(|r| r.map(|o| o.unwrap_or_else(|| default.clone())))
+    }
+}
+
+impl<I, T, E> FusedIterator for InnerUnwrapOr<I, T>
+where
+    I: FusedIterator<Item = Result<Option<T>, E>>,
+    T: Clone,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct InnerUnwrapOrDefault<I> {
+    iter: I,
+}
+
+impl<I, T, E> Iterator for InnerUnwrapOrDefault<I>
+where
+    I: Iterator<Item = Result<Option<T>, E>>,
+    T: Default,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|r| r.map(Option::unwrap_or_default))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter
+            .fold(init, move |acc, r| g(acc, r.map(Option::unwrap_or_default)))
+    }
+}
+
+impl<I, T, E> DoubleEndedIterator for InnerUnwrapOrDefault<I>
+where
+    I: DoubleEndedIterator<Item = Result<Option<T>, E>>,
+    T: Default,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|r| r.map(Option::unwrap_or_default))
+    }
+}
+
+impl<I, T, E> FusedIterator for InnerUnwrapOrDefault<I>
+where
+    I: FusedIterator<Item = Result<Option<T>, E>>,
+    T: Default,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_inner_ok_or_else_exact_size() {
+        let v: Vec<Result<Option<usize>, usize>> = vec![Ok(Some(1)), Ok(None), Err(3)];
+        let iter = v.into_iter().map_inner_ok_or_else(|| 0);
+
+        assert_eq!(iter.len(), 3);
+    }
+
+    #[test]
+    fn test_map_inner_ok_or_else_rev() {
+        let v: Vec<Result<Option<usize>, usize>> = vec![Ok(Some(1)), Ok(None), Err(3)];
+        let res: Vec<_> = v.into_iter().map_inner_ok_or_else(|| 0).rev().collect();
+
+        assert_eq!(res, vec![Err(3), Err(0), Ok(1)]);
+    }
+
+    #[test]
+    fn test_filter_inner_none_rev() {
+        let v: Vec<Result<Option<usize>, usize>> = vec![Ok(Some(1)), Ok(None), Err(3), Ok(None)];
+        let res: Vec<_> = v.into_iter().filter_inner_none().rev().collect();
+
+        assert_eq!(res, vec![Err(3), Ok(1)]);
+    }
 }
@@ -4,12 +4,35 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct OnErr<I, O, E, F>(I, F)
 where
     I: Iterator<Item = Result<O, E>>,
     F: FnMut(&E);
 
+impl<I, O, E, F> Clone for OnErr<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>> + Clone,
+    F: FnMut(&E) + Clone,
+{
+    fn clone(&self) -> Self {
+        OnErr(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<I, O, E, F> fmt::Debug for OnErr<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>> + fmt::Debug,
+    F: FnMut(&E),
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OnErr").field(&self.0).finish()
+    }
+}
+
 /// Extension trait for `Iterator<Item = Result<T, E>>` to do something on `Err(_)`
 pub trait OnErrDo<I, O, E, F>
 where
@@ -18,6 +41,9 @@ where
 {
     /// Apply a sideffect on each `Err`
     ///
+    /// As the side effect is the point of this adapter, it is performed for every item, including
+    /// items skipped via `nth`, `count` or `last`.
+    ///
     /// ```
     /// use resiter::onerr::OnErrDo;
     /// use std::str::FromStr;
@@ -47,6 +73,47 @@ where
     }
 }
 
+/// Extension trait for `Iterator<Item = Result<T, E>>` to do something on the first `Err(_)`
+pub trait OnFirstErrDo<O, E>: Sized {
+    /// Apply a sideffect on the first `Err` only
+    ///
+    /// All items are passed through unchanged.
+    ///
+    /// ```
+    /// use resiter::onerr::OnFirstErrDo;
+    /// use std::str::FromStr;
+    ///
+    /// let mut warnings = 0;
+    /// let res: Vec<Result<usize, ::std::num::ParseIntError>> = ["1", "2", "a", "b", "5"]
+    ///     .iter()
+    ///     .map(|e| usize::from_str(e))
+    ///     .on_first_err(|_| warnings += 1)
+    ///     .collect();
+    ///
+    /// assert_eq!(res.len(), 5);
+    /// assert_eq!(warnings, 1);
+    /// ```
+    fn on_first_err<F>(self, _: F) -> OnFirstErr<Self, F>
+    where
+        F: FnOnce(&E);
+}
+
+impl<I, O, E> OnFirstErrDo<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn on_first_err<F>(self, f: F) -> OnFirstErr<Self, F>
+    where
+        F: FnOnce(&E),
+    {
+        OnFirstErr {
+            iter: self,
+            f: Some(f),
+        }
+    }
+}
+
 impl<I, O, E, F> Iterator for OnErr<I, O, E, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -54,6 +121,8 @@ where
 {
     type Item = Result<O, E>;
 
+    // `Result::inspect` is only available since Rust 1.76, above our MSRV
+    #[allow(unknown_lints, clippy::manual_inspect)]
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|r| {
             r.map_err(|e| {
@@ -62,4 +131,176 @@ where
             })
         })
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.1;
+        self.0.fold(init, move |acc, r| {
+            if let Err(ref e) = r {
+                f(e);
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for OnErr<I, O, E, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&E),
+{
+    // `Result::inspect` is only available since Rust 1.76, above our MSRV
+    #[allow(unknown_lints, clippy::manual_inspect)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|r| {
+            r.map_err(|e| {
+                (self.1)(&e);
+                e
+            })
+        })
+    }
+}
+
+impl<I, O, E, F> FusedIterator for OnErr<I, O, E, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&E),
+{
+}
+
+impl<I, O, E, F> ExactSizeIterator for OnErr<I, O, E, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(&E),
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct OnFirstErr<I, F> {
+    iter: I,
+    f: Option<F>,
+}
+
+impl<I, F> fmt::Debug for OnFirstErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OnFirstErr")
+            .field("iter", &self.iter)
+            .field("fired", &self.f.is_none())
+            .finish()
+    }
+}
+
+impl<I, F> OnFirstErr<I, F> {
+    #[inline]
+    fn inspect<O, E>(&mut self, r: &Result<O, E>)
+    where
+        F: FnOnce(&E),
+    {
+        if let Err(ref e) = *r {
+            if let Some(f) = self.f.take() {
+                f(e);
+            }
+        }
+    }
+}
+
+impl<I, O, E, F> Iterator for OnFirstErr<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnOnce(&E),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        self.inspect(&r);
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| {
+            if let Err(ref e) = r {
+                if let Some(f) = f.take() {
+                    f(e);
+                }
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for OnFirstErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnOnce(&E),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        self.inspect(&r);
+        Some(r)
+    }
+}
+
+impl<I, O, E, F> FusedIterator for OnFirstErr<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnOnce(&E),
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_err_exact_size() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mut iter = v.into_iter().on_err(|_| {});
+
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+    }
+
+    #[test]
+    fn test_on_err_rev() {
+        let mut errs = Vec::new();
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2), Err(3)];
+        let res: Vec<_> = v.into_iter().on_err(|e| errs.push(*e)).rev().collect();
+
+        assert_eq!(res, vec![Err(3), Ok(2), Err(1)]);
+        assert_eq!(errs, vec![3, 1]);
+    }
+
+    #[test]
+    fn test_on_first_err_fires_once() {
+        let mut first = None;
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3)];
+        let res: Vec<_> = v.into_iter().on_first_err(|e| first = Some(*e)).collect();
+
+        assert_eq!(res, vec![Ok(1), Err(2), Err(3)]);
+        assert_eq!(first, Some(2));
+    }
 }
@@ -4,12 +4,35 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 pub struct OnOk<I, O, E, F>(I, F)
 where
     I: Iterator<Item = Result<O, E>>,
     F: FnMut(&O);
 
+impl<I, O, E, F> Clone for OnOk<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>> + Clone,
+    F: FnMut(&O) + Clone,
+{
+    fn clone(&self) -> Self {
+        OnOk(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<I, O, E, F> fmt::Debug for OnOk<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>> + fmt::Debug,
+    F: FnMut(&O),
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("OnOk").field(&self.0).finish()
+    }
+}
+
 /// Extension trait for `Iterator<Item = Result<T, E>>` to do something on `Ok(_)`
 pub trait OnOkDo<I, O, E, F>
 where
@@ -18,6 +41,9 @@ where
 {
     /// Perform a side effect on each Ok value
     ///
+    /// As the side effect is the point of this adapter, it is performed for every item, including
+    /// items skipped via `nth`, `count` or `last`.
+    ///
     /// ```
     /// use resiter::onok::OnOkDo;
     /// use std::str::FromStr;
@@ -52,6 +78,8 @@ where
 {
     type Item = Result<O, E>;
 
+    // `Result::inspect` is only available since Rust 1.76, above our MSRV
+    #[allow(unknown_lints, clippy::manual_inspect)]
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(|r| {
             r.map(|o| {
@@ -60,4 +88,88 @@ where
             })
         })
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.1;
+        self.0.fold(init, move |acc, r| {
+            if let Ok(ref o) = r {
+                f(o);
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for OnOk<I, O, E, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&O),
+{
+    // `Result::inspect` is only available since Rust 1.76, above our MSRV
+    #[allow(unknown_lints, clippy::manual_inspect)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|r| {
+            r.map(|o| {
+                (self.1)(&o);
+                o
+            })
+        })
+    }
+}
+
+impl<I, O, E, F> FusedIterator for OnOk<I, O, E, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&O),
+{
+}
+
+impl<I, O, E, F> ExactSizeIterator for OnOk<I, O, E, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(&O),
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_ok_count_performs_side_effect() {
+        let mut seen = 0;
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+
+        assert_eq!(v.into_iter().on_ok(|_| seen += 1).count(), 3);
+        assert_eq!(seen, 2);
+    }
+
+    #[test]
+    fn test_on_ok_exact_size() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mut iter = v.into_iter().on_ok(|_| {});
+
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+    }
+
+    #[test]
+    fn test_on_ok_rev() {
+        let mut oks = Vec::new();
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let res: Vec<_> = v.into_iter().on_ok(|o| oks.push(*o)).rev().collect();
+
+        assert_eq!(res, vec![Ok(3), Err(2), Ok(1)]);
+        assert_eq!(oks, vec![3, 1]);
+    }
 }
@@ -0,0 +1,102 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Option<T>>` to selectively transform the `Some` values.
+pub trait OptionAndThen<T>: Sized {
+    /// Map `Some` items selectively, possibly converting them to `None`
+    ///
+    /// ```
+    /// use resiter::option::and_then::OptionAndThen;
+    ///
+    /// let mapped: Vec<_> = vec![Some(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .and_then_some(|i| if i < 2 { Some(2 * i) } else { None })
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped, vec![Some(2), None, None]);
+    /// ```
+    fn and_then_some<F, U>(self, _: F) -> AndThenSome<Self, F>
+    where
+        F: FnMut(T) -> Option<U>;
+}
+
+impl<I, T> OptionAndThen<T> for I
+where
+    I: Iterator<Item = Option<T>> + Sized,
+{
+    #[inline]
+    fn and_then_some<F, U>(self, f: F) -> AndThenSome<Self, F>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        AndThenSome { iter: self, f }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AndThenSome<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for AndThenSome<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AndThenSome")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, T, F, U> Iterator for AndThenSome<I, F>
+where
+    I: Iterator<Item = Option<T>>,
+    F: FnMut(T) -> Option<U>,
+{
+    type Item = Option<U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|o| o.and_then(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, o| g(acc, o.and_then(&mut f)))
+    }
+}
+
+impl<I, T, F, U> DoubleEndedIterator for AndThenSome<I, F>
+where
+    I: DoubleEndedIterator<Item = Option<T>>,
+    F: FnMut(T) -> Option<U>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|o| o.and_then(&mut self.f))
+    }
+}
+
+impl<I, T, F, U> FusedIterator for AndThenSome<I, F>
+where
+    I: FusedIterator<Item = Option<T>>,
+    F: FnMut(T) -> Option<U>,
+{
+}
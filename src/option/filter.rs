@@ -0,0 +1,128 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Option<T>>` to filter the `Some` values.
+pub trait OptionFilter<T>: Sized {
+    /// Filter `Some` items while keeping `None`
+    ///
+    /// ```
+    /// use resiter::option::filter::OptionFilter;
+    ///
+    /// let filtered: Vec<_> = vec![Some(1), None, Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .filter_some(|i| i % 2 == 0)
+    ///     .collect();
+    ///
+    /// assert_eq!(filtered, vec![None, Some(2)]);
+    /// ```
+    fn filter_some<F>(self, _: F) -> FilterSome<Self, F>
+    where
+        F: FnMut(&T) -> bool;
+}
+
+impl<I, T> OptionFilter<T> for I
+where
+    I: Iterator<Item = Option<T>> + Sized,
+{
+    #[inline]
+    fn filter_some<F>(self, f: F) -> FilterSome<Self, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        FilterSome { iter: self, f }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct FilterSome<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for FilterSome<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterSome")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, T, F> Iterator for FilterSome<I, F>
+where
+    I: Iterator<Item = Option<T>>,
+    F: FnMut(&T) -> bool,
+{
+    type Item = Option<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some(Some(x)) => {
+                    if (self.f)(&x) {
+                        return Some(Some(x));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, o| match o {
+            Some(x) => {
+                if f(&x) {
+                    g(acc, Some(x))
+                } else {
+                    acc
+                }
+            }
+            None => g(acc, None),
+        })
+    }
+}
+
+impl<I, T, F> DoubleEndedIterator for FilterSome<I, F>
+where
+    I: DoubleEndedIterator<Item = Option<T>>,
+    F: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back() {
+                Some(Some(x)) => {
+                    if (self.f)(&x) {
+                        return Some(Some(x));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+impl<I, T, F> FusedIterator for FilterSome<I, F>
+where
+    I: FusedIterator<Item = Option<T>>,
+    F: FnMut(&T) -> bool,
+{
+}
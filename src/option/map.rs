@@ -0,0 +1,115 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Option<T>>` to transform the `Some` values.
+pub trait OptionMap<T>: Sized {
+    /// Map all `Some` items while leaving `None` as is
+    ///
+    /// ```
+    /// use resiter::option::map::OptionMap;
+    ///
+    /// let mapped: Vec<_> = vec![Some(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .map_some(|i| 2 * i)
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped, vec![Some(2), None, Some(6)]);
+    /// ```
+    fn map_some<F, U>(self, _: F) -> MapSome<Self, F>
+    where
+        F: FnMut(T) -> U;
+}
+
+impl<I, T> OptionMap<T> for I
+where
+    I: Iterator<Item = Option<T>> + Sized,
+{
+    #[inline]
+    fn map_some<F, U>(self, f: F) -> MapSome<Self, F>
+    where
+        F: FnMut(T) -> U,
+    {
+        MapSome { iter: self, f }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapSome<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for MapSome<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapSome").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, T, F, U> Iterator for MapSome<I, F>
+where
+    I: Iterator<Item = Option<T>>,
+    F: FnMut(T) -> U,
+{
+    type Item = Option<U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|o| o.map(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, o| g(acc, o.map(&mut f)))
+    }
+}
+
+impl<I, T, F, U> DoubleEndedIterator for MapSome<I, F>
+where
+    I: DoubleEndedIterator<Item = Option<T>>,
+    F: FnMut(T) -> U,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|o| o.map(&mut self.f))
+    }
+}
+
+impl<I, T, F, U> FusedIterator for MapSome<I, F>
+where
+    I: FusedIterator<Item = Option<T>>,
+    F: FnMut(T) -> U,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_some_rev() {
+        let res: Vec<_> = vec![Some(1), None, Some(3)]
+            .into_iter()
+            .map_some(|i| 2 * i)
+            .rev()
+            .collect();
+
+        assert_eq!(res, vec![Some(6), None, Some(2)]);
+    }
+}
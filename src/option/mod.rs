@@ -0,0 +1,50 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions for `Iterator<Item = Option<T>>`
+//!
+//! The modules in here are the counterpart of the `Result` extensions of this crate for
+//! iterators over `Option`s:
+//!
+//! ```
+//! use resiter::option::prelude::*;
+//!
+//! let doubles: Vec<_> = vec![Some(1), None, Some(3), Some(4)]
+//!     .into_iter()
+//!     .map_some(|i| 2 * i)
+//!     .filter_some(|i| *i > 2)
+//!     .somes()
+//!     .collect();
+//!
+//! assert_eq!(doubles, vec![6, 8]);
+//! ```
+
+pub mod and_then;
+pub mod filter;
+pub mod map;
+pub mod ok_or_else;
+pub mod on_none;
+pub mod somes;
+
+pub use self::and_then::OptionAndThen;
+pub use self::filter::OptionFilter;
+pub use self::map::OptionMap;
+pub use self::ok_or_else::OptionOkOrElse;
+pub use self::on_none::OnNoneDo;
+pub use self::somes::GetSomes;
+
+/// Prelude for the `Option` extensions
+///
+/// Imports all `Option` extension traits, so you can `use resiter::option::prelude::*;` in your
+/// crate.
+pub mod prelude {
+    pub use super::and_then::*;
+    pub use super::filter::*;
+    pub use super::map::*;
+    pub use super::ok_or_else::*;
+    pub use super::on_none::*;
+    pub use super::somes::*;
+}
@@ -0,0 +1,102 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for doing `Iterator<Item = Option<T>>`  ->  `Iterator<Item = Result<T, E>>`
+pub trait OptionOkOrElse<T>: Sized {
+    /// Turn every `Some` into an `Ok` and every `None` into an `Err` produced by the closure
+    ///
+    /// ```
+    /// use resiter::option::ok_or_else::OptionOkOrElse;
+    ///
+    /// let res: Vec<_> = vec![Some(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .ok_or_else(|| "missing")
+    ///     .collect();
+    ///
+    /// assert_eq!(res, vec![Ok(1), Err("missing"), Ok(3)]);
+    /// ```
+    fn ok_or_else<F, E>(self, _: F) -> OkOrElse<Self, F>
+    where
+        F: FnMut() -> E;
+}
+
+impl<I, T> OptionOkOrElse<T> for I
+where
+    I: Iterator<Item = Option<T>> + Sized,
+{
+    #[inline]
+    fn ok_or_else<F, E>(self, f: F) -> OkOrElse<Self, F>
+    where
+        F: FnMut() -> E,
+    {
+        OkOrElse { iter: self, f }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct OkOrElse<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for OkOrElse<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OkOrElse")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, T, F, E> Iterator for OkOrElse<I, F>
+where
+    I: Iterator<Item = Option<T>>,
+    F: FnMut() -> E,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|o| o.ok_or_else(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, o| g(acc, o.ok_or_else(&mut f)))
+    }
+}
+
+impl<I, T, F, E> DoubleEndedIterator for OkOrElse<I, F>
+where
+    I: DoubleEndedIterator<Item = Option<T>>,
+    F: FnMut() -> E,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|o| o.ok_or_else(&mut self.f))
+    }
+}
+
+impl<I, T, F, E> FusedIterator for OkOrElse<I, F>
+where
+    I: FusedIterator<Item = Option<T>>,
+    F: FnMut() -> E,
+{
+}
@@ -0,0 +1,210 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Option<T>>` to do something on `None` or `Some(_)`
+pub trait OnNoneDo<T>: Sized {
+    /// Apply a side effect on each `None`
+    ///
+    /// ```
+    /// use resiter::option::on_none::OnNoneDo;
+    ///
+    /// let mut missing = 0;
+    /// let _: Vec<_> = vec![Some(1), None, Some(3), None]
+    ///     .into_iter()
+    ///     .on_none(|| missing += 1)
+    ///     .collect();
+    ///
+    /// assert_eq!(missing, 2);
+    /// ```
+    fn on_none<F>(self, _: F) -> OnNone<Self, F>
+    where
+        F: FnMut();
+
+    /// Apply a side effect on each `Some` value
+    ///
+    /// ```
+    /// use resiter::option::on_none::OnNoneDo;
+    ///
+    /// let mut somes = Vec::new();
+    /// let _: Vec<_> = vec![Some(1), None, Some(3), None]
+    ///     .into_iter()
+    ///     .on_some(|i| somes.push(*i))
+    ///     .collect();
+    ///
+    /// assert_eq!(somes, vec![1, 3]);
+    /// ```
+    fn on_some<F>(self, _: F) -> OnSome<Self, F>
+    where
+        F: FnMut(&T);
+}
+
+impl<I, T> OnNoneDo<T> for I
+where
+    I: Iterator<Item = Option<T>> + Sized,
+{
+    #[inline]
+    fn on_none<F>(self, f: F) -> OnNone<Self, F>
+    where
+        F: FnMut(),
+    {
+        OnNone { iter: self, f }
+    }
+
+    #[inline]
+    fn on_some<F>(self, f: F) -> OnSome<Self, F>
+    where
+        F: FnMut(&T),
+    {
+        OnSome { iter: self, f }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct OnNone<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for OnNone<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OnNone").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, T, F> Iterator for OnNone<I, F>
+where
+    I: Iterator<Item = Option<T>>,
+    F: FnMut(),
+{
+    type Item = Option<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let o = self.iter.next()?;
+        if o.is_none() {
+            (self.f)();
+        }
+        Some(o)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, o| {
+            if o.is_none() {
+                f();
+            }
+            g(acc, o)
+        })
+    }
+}
+
+impl<I, T, F> DoubleEndedIterator for OnNone<I, F>
+where
+    I: DoubleEndedIterator<Item = Option<T>>,
+    F: FnMut(),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let o = self.iter.next_back()?;
+        if o.is_none() {
+            (self.f)();
+        }
+        Some(o)
+    }
+}
+
+impl<I, T, F> FusedIterator for OnNone<I, F>
+where
+    I: FusedIterator<Item = Option<T>>,
+    F: FnMut(),
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct OnSome<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for OnSome<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OnSome").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, T, F> Iterator for OnSome<I, F>
+where
+    I: Iterator<Item = Option<T>>,
+    F: FnMut(&T),
+{
+    type Item = Option<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let o = self.iter.next()?;
+        if let Some(ref t) = o {
+            (self.f)(t);
+        }
+        Some(o)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, o| {
+            if let Some(ref t) = o {
+                f(t);
+            }
+            g(acc, o)
+        })
+    }
+}
+
+impl<I, T, F> DoubleEndedIterator for OnSome<I, F>
+where
+    I: DoubleEndedIterator<Item = Option<T>>,
+    F: FnMut(&T),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let o = self.iter.next_back()?;
+        if let Some(ref t) = o {
+            (self.f)(t);
+        }
+        Some(o)
+    }
+}
+
+impl<I, T, F> FusedIterator for OnSome<I, F>
+where
+    I: FusedIterator<Item = Option<T>>,
+    F: FnMut(&T),
+{
+}
@@ -0,0 +1,67 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::iter::FilterMap;
+
+/// Extension trait for `Iterator<Item = Option<T>>` to get all `Some`s or `None`s
+#[allow(clippy::type_complexity)]
+pub trait GetSomes<T>: Sized {
+    /// Iterate over every `Some` value while ignoring every `None`
+    ///
+    /// ```
+    /// use resiter::option::somes::GetSomes;
+    ///
+    /// let somes: Vec<_> = vec![Some(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .somes()
+    ///     .collect();
+    ///
+    /// assert_eq!(somes, vec![1, 3]);
+    /// ```
+    fn somes(self) -> FilterMap<Self, fn(Option<T>) -> Option<T>>;
+
+    /// Iterate over every `None` while ignoring every `Some`
+    ///
+    /// ```
+    /// use resiter::option::somes::GetSomes;
+    ///
+    /// let nones = vec![Some(1), None, Some(3), None]
+    ///     .into_iter()
+    ///     .nones()
+    ///     .count();
+    ///
+    /// assert_eq!(nones, 2);
+    /// ```
+    fn nones(self) -> FilterMap<Self, fn(Option<T>) -> Option<()>>;
+}
+
+impl<T, I> GetSomes<T> for I
+where
+    I: Iterator<Item = Option<T>> + Sized,
+{
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn somes(self) -> FilterMap<Self, fn(Option<T>) -> Option<T>> {
+        self.filter_map(get_some)
+    }
+
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn nones(self) -> FilterMap<Self, fn(Option<T>) -> Option<()>> {
+        self.filter_map(get_none)
+    }
+}
+
+fn get_some<T>(o: Option<T>) -> Option<T> {
+    o
+}
+
+fn get_none<T>(o: Option<T>) -> Option<()> {
+    match o {
+        Some(_) => None,
+        None => Some(()),
+    }
+}
@@ -0,0 +1,457 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions for rayon's `ParallelIterator<Item = Result<O, E>>`
+//!
+//! Only available with the `rayon` feature.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use rayon::iter::plumbing::UnindexedConsumer;
+use rayon::iter::{Either, ParallelIterator};
+
+/// Extension trait for `ParallelIterator<Item = Result<O, E>>`
+pub trait ParallelResults<O, E>: Sized
+where
+    O: Send,
+    E: Send,
+{
+    /// Map all `Ok` items in parallel while leaving `Err` as is
+    ///
+    /// ```
+    /// extern crate rayon;
+    /// extern crate resiter;
+    ///
+    /// use rayon::prelude::*;
+    /// use resiter::parallel::ParallelResults;
+    /// use std::str::FromStr;
+    ///
+    /// let mapped: Vec<_> = vec!["1", "2", "a", "4"]
+    ///     .into_par_iter()
+    ///     .map(usize::from_str)
+    ///     .par_map_ok(|i| 2 * i)
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped[0], Ok(2));
+    /// assert_eq!(mapped[1], Ok(4));
+    /// assert!(mapped[2].is_err());
+    /// assert_eq!(mapped[3], Ok(8));
+    /// ```
+    fn par_map_ok<F, O2>(self, _: F) -> ParMapOk<Self, F>
+    where
+        F: Fn(O) -> O2 + Sync + Send,
+        O2: Send;
+
+    /// Map all `Err` items in parallel while leaving `Ok` as is
+    ///
+    /// ```
+    /// extern crate rayon;
+    /// extern crate resiter;
+    ///
+    /// use rayon::prelude::*;
+    /// use resiter::parallel::ParallelResults;
+    /// use std::str::FromStr;
+    ///
+    /// let mapped: Vec<_> = vec!["1", "a"]
+    ///     .into_par_iter()
+    ///     .map(usize::from_str)
+    ///     .par_map_err(|e| e.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped, vec![Ok(1), Err("invalid digit found in string".to_string())]);
+    /// ```
+    fn par_map_err<F, E2>(self, _: F) -> ParMapErr<Self, F>
+    where
+        F: Fn(E) -> E2 + Sync + Send,
+        E2: Send;
+
+    /// Filter `Ok` items in parallel while keeping `Err`
+    ///
+    /// ```
+    /// extern crate rayon;
+    /// extern crate resiter;
+    ///
+    /// use rayon::prelude::*;
+    /// use resiter::parallel::ParallelResults;
+    /// use std::str::FromStr;
+    ///
+    /// let filtered: Vec<_> = vec!["1", "2", "a", "4"]
+    ///     .into_par_iter()
+    ///     .map(usize::from_str)
+    ///     .par_filter_ok(|i| i % 2 == 0)
+    ///     .collect();
+    ///
+    /// assert_eq!(filtered.len(), 3);
+    /// assert_eq!(filtered[0], Ok(2));
+    /// assert!(filtered[1].is_err());
+    /// ```
+    fn par_filter_ok<F>(self, _: F) -> ParFilterOk<Self, F>
+    where
+        F: Fn(&O) -> bool + Sync + Send;
+
+    /// Filter `Err` items in parallel while keeping `Ok`
+    ///
+    /// ```
+    /// extern crate rayon;
+    /// extern crate resiter;
+    ///
+    /// use rayon::prelude::*;
+    /// use resiter::parallel::ParallelResults;
+    /// use std::str::FromStr;
+    ///
+    /// let filtered: Vec<_> = vec!["1", "2", "a", "4"]
+    ///     .into_par_iter()
+    ///     .map(usize::from_str)
+    ///     .par_filter_err(|_| false)
+    ///     .collect();
+    ///
+    /// assert_eq!(filtered, vec![Ok(1), Ok(2), Ok(4)]);
+    /// ```
+    fn par_filter_err<F>(self, _: F) -> ParFilterErr<Self, F>
+    where
+        F: Fn(&E) -> bool + Sync + Send;
+
+    /// Iterate over every `Ok` in parallel while ignoring every `Err`
+    ///
+    /// ```
+    /// extern crate rayon;
+    /// extern crate resiter;
+    ///
+    /// use rayon::prelude::*;
+    /// use resiter::parallel::ParallelResults;
+    /// use std::str::FromStr;
+    ///
+    /// let sum: usize = vec!["1", "2", "a", "4"]
+    ///     .into_par_iter()
+    ///     .map(usize::from_str)
+    ///     .par_oks()
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 7);
+    /// ```
+    fn par_oks(self) -> ParOks<Self>;
+
+    /// Iterate over every `Err` in parallel while ignoring every `Ok`
+    ///
+    /// ```
+    /// extern crate rayon;
+    /// extern crate resiter;
+    ///
+    /// use rayon::prelude::*;
+    /// use resiter::parallel::ParallelResults;
+    /// use std::str::FromStr;
+    ///
+    /// let errs = vec!["1", "2", "a", "4", "b"]
+    ///     .into_par_iter()
+    ///     .map(usize::from_str)
+    ///     .par_errors()
+    ///     .count();
+    ///
+    /// assert_eq!(errs, 2);
+    /// ```
+    fn par_errors(self) -> ParErrors<Self>;
+
+    /// Drain the iterator in parallel and return all `Ok` values and all `Err` values
+    ///
+    /// ```
+    /// extern crate rayon;
+    /// extern crate resiter;
+    ///
+    /// use rayon::prelude::*;
+    /// use resiter::parallel::ParallelResults;
+    /// use std::str::FromStr;
+    ///
+    /// let (oks, errs) = vec!["1", "2", "a", "4", "b"]
+    ///     .into_par_iter()
+    ///     .map(usize::from_str)
+    ///     .par_partition_results();
+    ///
+    /// assert_eq!(oks, vec![1, 2, 4]);
+    /// assert_eq!(errs.len(), 2);
+    /// ```
+    fn par_partition_results(self) -> (Vec<O>, Vec<E>);
+}
+
+impl<I, O, E> ParallelResults<O, E> for I
+where
+    I: ParallelIterator<Item = Result<O, E>>,
+    O: Send,
+    E: Send,
+{
+    #[inline]
+    fn par_map_ok<F, O2>(self, f: F) -> ParMapOk<Self, F>
+    where
+        F: Fn(O) -> O2 + Sync + Send,
+        O2: Send,
+    {
+        ParMapOk { iter: self, f }
+    }
+
+    #[inline]
+    fn par_map_err<F, E2>(self, f: F) -> ParMapErr<Self, F>
+    where
+        F: Fn(E) -> E2 + Sync + Send,
+        E2: Send,
+    {
+        ParMapErr { iter: self, f }
+    }
+
+    #[inline]
+    fn par_filter_ok<F>(self, f: F) -> ParFilterOk<Self, F>
+    where
+        F: Fn(&O) -> bool + Sync + Send,
+    {
+        ParFilterOk { iter: self, f }
+    }
+
+    #[inline]
+    fn par_filter_err<F>(self, f: F) -> ParFilterErr<Self, F>
+    where
+        F: Fn(&E) -> bool + Sync + Send,
+    {
+        ParFilterErr { iter: self, f }
+    }
+
+    #[inline]
+    fn par_oks(self) -> ParOks<Self> {
+        ParOks { iter: self }
+    }
+
+    #[inline]
+    fn par_errors(self) -> ParErrors<Self> {
+        ParErrors { iter: self }
+    }
+
+    fn par_partition_results(self) -> (Vec<O>, Vec<E>) {
+        self.partition_map(|r| match r {
+            Ok(o) => Either::Left(o),
+            Err(e) => Either::Right(e),
+        })
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ParMapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for ParMapOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParMapOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, O2> ParallelIterator for ParMapOk<I, F>
+where
+    I: ParallelIterator<Item = Result<O, E>>,
+    F: Fn(O) -> O2 + Sync + Send,
+    O2: Send,
+    E: Send,
+{
+    type Item = Result<O2, E>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let f = self.f;
+        self.iter.map(|r| r.map(&f)).drive_unindexed(consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        self.iter.opt_len()
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ParMapErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for ParMapErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParMapErr")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, E2> ParallelIterator for ParMapErr<I, F>
+where
+    I: ParallelIterator<Item = Result<O, E>>,
+    F: Fn(E) -> E2 + Sync + Send,
+    O: Send,
+    E2: Send,
+{
+    type Item = Result<O, E2>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let f = self.f;
+        self.iter.map(|r| r.map_err(&f)).drive_unindexed(consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        self.iter.opt_len()
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ParFilterOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for ParFilterOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParFilterOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F> ParallelIterator for ParFilterOk<I, F>
+where
+    I: ParallelIterator<Item = Result<O, E>>,
+    F: Fn(&O) -> bool + Sync + Send,
+    O: Send,
+    E: Send,
+{
+    type Item = Result<O, E>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let f = self.f;
+        self.iter
+            .filter(|r| r.as_ref().map(&f).unwrap_or(true))
+            .drive_unindexed(consumer)
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ParFilterErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for ParFilterErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParFilterErr")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F> ParallelIterator for ParFilterErr<I, F>
+where
+    I: ParallelIterator<Item = Result<O, E>>,
+    F: Fn(&E) -> bool + Sync + Send,
+    O: Send,
+    E: Send,
+{
+    type Item = Result<O, E>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let f = self.f;
+        self.iter
+            .filter(|r| r.as_ref().err().map(&f).unwrap_or(true))
+            .drive_unindexed(consumer)
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ParOks<I> {
+    iter: I,
+}
+
+impl<I> fmt::Debug for ParOks<I>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParOks").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, O, E> ParallelIterator for ParOks<I>
+where
+    I: ParallelIterator<Item = Result<O, E>>,
+    O: Send,
+    E: Send,
+{
+    type Item = O;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.iter.filter_map(Result::ok).drive_unindexed(consumer)
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct ParErrors<I> {
+    iter: I,
+}
+
+impl<I> fmt::Debug for ParErrors<I>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ParErrors")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E> ParallelIterator for ParErrors<I>
+where
+    I: ParallelIterator<Item = Result<O, E>>,
+    O: Send,
+    E: Send,
+{
+    type Item = E;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.iter.filter_map(Result::err).drive_unindexed(consumer)
+    }
+}
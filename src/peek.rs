@@ -0,0 +1,186 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to look ahead at the next item
+pub trait Peek<O, E>: Sized + Iterator<Item = Result<O, E>> {
+    /// Create an iterator which can peek at the next `Ok` or `Err` without consuming it
+    ///
+    /// ```
+    /// use resiter::peek::Peek;
+    /// use std::str::FromStr;
+    ///
+    /// let mut iter = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .peekable_result();
+    ///
+    /// assert_eq!(iter.peek_ok(), Some(&1));
+    /// assert_eq!(iter.next_if_ok(|i| *i > 1), None);
+    /// assert_eq!(iter.next_if_ok(|i| *i == 1), Some(1));
+    ///
+    /// assert_eq!(iter.peek_ok(), None);
+    /// assert!(iter.peek_err().is_some());
+    /// assert!(iter.next().unwrap().is_err());
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// ```
+    fn peekable_result(self) -> PeekableResult<Self>;
+}
+
+impl<I, O, E> Peek<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn peekable_result(self) -> PeekableResult<Self> {
+        PeekableResult {
+            iter: self,
+            peeked: None,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct PeekableResult<I: Iterator> {
+    iter: I,
+    /// Remember a peeked value, even if it was `None`
+    peeked: Option<Option<I::Item>>,
+}
+
+impl<I> fmt::Debug for PeekableResult<I>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PeekableResult")
+            .field("iter", &self.iter)
+            .field("peeked", &self.peeked)
+            .finish()
+    }
+}
+
+impl<I, O, E> PeekableResult<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    /// Return a reference to the next item without advancing the iterator
+    #[inline]
+    pub fn peek(&mut self) -> Option<&Result<O, E>> {
+        let iter = &mut self.iter;
+        self.peeked.get_or_insert_with(|| iter.next()).as_ref()
+    }
+
+    /// Return a reference to the next value if it is an `Ok`, without advancing the iterator
+    #[inline]
+    pub fn peek_ok<'a>(&'a mut self) -> Option<&'a O>
+    where
+        E: 'a,
+    {
+        self.peek().and_then(|r| r.as_ref().ok())
+    }
+
+    /// Return a reference to the next value if it is an `Err`, without advancing the iterator
+    #[inline]
+    pub fn peek_err<'a>(&'a mut self) -> Option<&'a E>
+    where
+        O: 'a,
+    {
+        self.peek().and_then(|r| r.as_ref().err())
+    }
+
+    /// Consume and return the next value if it is an `Ok` for which `pred` returns `true`
+    ///
+    /// Otherwise the next item is left in place, so buffered errors are never lost.
+    pub fn next_if_ok<P>(&mut self, pred: P) -> Option<O>
+    where
+        P: FnOnce(&O) -> bool,
+    {
+        match self.next() {
+            Some(Ok(o)) if pred(&o) => Some(o),
+            other => {
+                self.peeked = Some(other);
+                None
+            }
+        }
+    }
+}
+
+impl<I, O, E> Iterator for PeekableResult<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.peeked.take() {
+            Some(v) => v,
+            None => self.iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peek_len = match self.peeked {
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        let (lo, hi) = self.iter.size_hint();
+        (
+            lo.saturating_add(peek_len),
+            hi.and_then(|x| x.checked_add(peek_len)),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let acc = match self.peeked {
+            Some(None) => return init,
+            Some(Some(v)) => g(init, v),
+            None => init,
+        };
+        self.iter.fold(acc, g)
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for PeekableResult<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.peeked.as_mut() {
+            Some(v @ Some(_)) => self.iter.next_back().or_else(|| v.take()),
+            Some(None) => None,
+            None => self.iter.next_back(),
+        }
+    }
+}
+
+impl<I, O, E> FusedIterator for PeekableResult<I> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peekable_result_next_back_returns_peeked() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2)];
+        let mut iter = v.into_iter().peekable_result();
+
+        assert_eq!(iter.peek_err(), Some(&1));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next_back(), Some(Ok(2)));
+        assert_eq!(iter.next_back(), Some(Err(1)));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,338 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Pluggable strategies for dealing with the errors of an iterator
+//!
+//! An [`ErrorPolicy`] decides what happens to every `Err` item: whether it is kept, reported or
+//! dropped, and whether the iteration goes on afterwards. [`ErrorHandling::handle_errors`] applies
+//! a policy and yields only the `Ok` values, so the error handling of a pipeline can be chosen at
+//! runtime. [`CollectUpTo`] requires the `alloc` feature.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::ControlFlow;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A strategy for dealing with the `Err` items of an iterator
+pub trait ErrorPolicy<E> {
+    /// Handle `error`, returning `ControlFlow::Break` to stop the iteration
+    fn handle(&mut self, error: E) -> ControlFlow<()>;
+}
+
+impl<E, P> ErrorPolicy<E> for &mut P
+where
+    P: ErrorPolicy<E> + ?Sized,
+{
+    #[inline]
+    fn handle(&mut self, error: E) -> ControlFlow<()> {
+        (**self).handle(error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E, P> ErrorPolicy<E> for Box<P>
+where
+    P: ErrorPolicy<E> + ?Sized,
+{
+    #[inline]
+    fn handle(&mut self, error: E) -> ControlFlow<()> {
+        (**self).handle(error)
+    }
+}
+
+/// Stop at the first error and keep it
+///
+/// Pass the policy by `&mut` to retrieve the error after the iteration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FailFast<E> {
+    error: Option<E>,
+}
+
+impl<E> FailFast<E> {
+    /// Create a policy which has not seen an error yet
+    #[inline]
+    pub fn new() -> Self {
+        FailFast { error: None }
+    }
+
+    /// The error which stopped the iteration, if any
+    #[inline]
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// Consume the policy and return the error which stopped the iteration, if any
+    #[inline]
+    pub fn into_error(self) -> Option<E> {
+        self.error
+    }
+}
+
+impl<E> Default for FailFast<E> {
+    #[inline]
+    fn default() -> Self {
+        FailFast::new()
+    }
+}
+
+impl<E> ErrorPolicy<E> for FailFast<E> {
+    #[inline]
+    fn handle(&mut self, error: E) -> ControlFlow<()> {
+        self.error = Some(error);
+        ControlFlow::Break(())
+    }
+}
+
+/// Drop every error and continue
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ignore;
+
+impl<E> ErrorPolicy<E> for Ignore {
+    #[inline]
+    fn handle(&mut self, _: E) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+/// Keep every error and continue, until more than a maximum number of errors occurred
+///
+/// The error exceeding the maximum is kept as well. Pass the policy by `&mut` to retrieve the
+/// errors after the iteration. Only available with the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectUpTo<E> {
+    max: usize,
+    errors: Vec<E>,
+}
+
+#[cfg(feature = "alloc")]
+impl<E> CollectUpTo<E> {
+    /// Create a policy which tolerates up to `max` errors
+    #[inline]
+    pub fn new(max: usize) -> Self {
+        CollectUpTo {
+            max,
+            errors: Vec::new(),
+        }
+    }
+
+    /// All errors seen so far
+    #[inline]
+    pub fn errors(&self) -> &[E] {
+        &self.errors
+    }
+
+    /// Consume the policy and return all errors seen
+    #[inline]
+    pub fn into_errors(self) -> Vec<E> {
+        self.errors
+    }
+
+    /// Whether more than the tolerated number of errors occurred
+    #[inline]
+    pub fn is_exceeded(&self) -> bool {
+        self.errors.len() > self.max
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> ErrorPolicy<E> for CollectUpTo<E> {
+    #[inline]
+    fn handle(&mut self, error: E) -> ControlFlow<()> {
+        self.errors.push(error);
+        if self.is_exceeded() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+/// Call the wrapped closure with every error and continue
+#[derive(Clone)]
+pub struct SideEffect<F>(pub F);
+
+impl<F> fmt::Debug for SideEffect<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SideEffect").finish()
+    }
+}
+
+impl<E, F> ErrorPolicy<E> for SideEffect<F>
+where
+    F: FnMut(E),
+{
+    #[inline]
+    fn handle(&mut self, error: E) -> ControlFlow<()> {
+        (self.0)(error);
+        ControlFlow::Continue(())
+    }
+}
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to deal with errors via an [`ErrorPolicy`]
+pub trait ErrorHandling<O, E>: Sized {
+    /// Pass every `Err` to `policy` and yield only the `Ok` values
+    ///
+    /// The iteration ends as soon as the policy returns `ControlFlow::Break`.
+    ///
+    /// ```
+    /// use resiter::policy::{ErrorHandling, ErrorPolicy, FailFast, Ignore};
+    /// use std::num::ParseIntError;
+    /// use std::str::FromStr;
+    ///
+    /// fn parse(strict: bool) -> Vec<usize> {
+    ///     let mut fail_fast = FailFast::new();
+    ///     let policy: &mut dyn ErrorPolicy<ParseIntError> = if strict {
+    ///         &mut fail_fast
+    ///     } else {
+    ///         &mut Ignore
+    ///     };
+    ///
+    ///     ["1", "a", "3"]
+    ///         .iter()
+    ///         .map(|txt| usize::from_str(txt))
+    ///         .handle_errors(policy)
+    ///         .collect()
+    /// }
+    ///
+    /// assert_eq!(parse(true), vec![1]);
+    /// assert_eq!(parse(false), vec![1, 3]);
+    ///
+    /// let mut policy = FailFast::new();
+    /// let oks: Vec<usize> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .handle_errors(&mut policy)
+    ///     .collect();
+    ///
+    /// assert_eq!(oks, vec![1]);
+    /// assert!(policy.error().is_some());
+    /// ```
+    fn handle_errors<P>(self, policy: P) -> HandleErrors<Self, P>
+    where
+        P: ErrorPolicy<E>;
+}
+
+impl<I, O, E> ErrorHandling<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn handle_errors<P>(self, policy: P) -> HandleErrors<Self, P>
+    where
+        P: ErrorPolicy<E>,
+    {
+        HandleErrors {
+            iter: self,
+            policy,
+            done: false,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct HandleErrors<I, P> {
+    iter: I,
+    policy: P,
+    done: bool,
+}
+
+impl<I, P> HandleErrors<I, P> {
+    /// Consume the adapter and return the policy
+    #[inline]
+    pub fn into_policy(self) -> P {
+        self.policy
+    }
+}
+
+impl<I, P> fmt::Debug for HandleErrors<I, P>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HandleErrors")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, O, E, P> Iterator for HandleErrors<I, P>
+where
+    I: Iterator<Item = Result<O, E>>,
+    P: ErrorPolicy<E>,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            match self.iter.next()? {
+                Ok(o) => return Some(o),
+                Err(e) => {
+                    if self.policy.handle(e).is_break() {
+                        self.done = true;
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, O, E, P> FusedIterator for HandleErrors<I, P>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    P: ErrorPolicy<E>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_handle_errors_collect_up_to() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Err(4), Ok(5), Err(6)];
+        let mut policy = CollectUpTo::new(1);
+        let oks: Vec<_> = v.into_iter().handle_errors(&mut policy).collect();
+
+        assert_eq!(oks, vec![1, 3]);
+        assert_eq!(policy.errors(), &[2, 4]);
+        assert!(policy.is_exceeded());
+    }
+
+    #[test]
+    fn test_handle_errors_side_effect() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2), Err(3)];
+        let mut seen = Vec::new();
+        let oks: Vec<_> = v
+            .into_iter()
+            .handle_errors(SideEffect(|e| seen.push(e)))
+            .collect();
+
+        assert_eq!(oks, vec![2]);
+        assert_eq!(seen, vec![1, 3]);
+    }
+}
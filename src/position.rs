@@ -0,0 +1,213 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Attach the position of the failing item to every error of an iterator
+//!
+//! The `std::error::Error` implementation of [`Positioned`] requires the `std` feature.
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// An error, together with the zero-based position of the item which produced it
+///
+/// The `Display` implementation renders the position and the wrapped error. With the `std`
+/// feature, `Positioned` implements `std::error::Error`, forwarding the source of the wrapped
+/// error.
+///
+/// ```
+/// use resiter::position::Positioned;
+///
+/// let err = Positioned::new(3, "invalid value");
+///
+/// assert_eq!(err.index(), 3);
+/// assert_eq!(err.error(), &"invalid value");
+/// assert_eq!(err.to_string(), "failed at item 3: invalid value");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Positioned<E> {
+    index: usize,
+    error: E,
+}
+
+impl<E> Positioned<E> {
+    /// Wrap `error`, which was produced by the item at position `index`
+    #[inline]
+    pub fn new(index: usize, error: E) -> Self {
+        Positioned { index, error }
+    }
+
+    /// The zero-based position of the item which produced the error
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The wrapped error
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Consume the wrapper and return the wrapped error
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+
+    /// Map the wrapped error, keeping the position
+    #[inline]
+    pub fn map<F, E2>(self, f: F) -> Positioned<E2>
+    where
+        F: FnOnce(E) -> E2,
+    {
+        Positioned {
+            index: self.index,
+            error: f(self.error),
+        }
+    }
+}
+
+impl<E> fmt::Display for Positioned<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed at item {}: {}", self.index, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> Error for Positioned<E>
+where
+    E: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to record the position of every error
+pub trait Positions<O, E>: Sized {
+    /// Wrap every `Err` into a [`Positioned`], recording the zero-based position of the item
+    ///
+    /// The position counts all items of the underlying iterator, not only the errors.
+    ///
+    /// ```
+    /// use resiter::position::Positions;
+    /// use std::str::FromStr;
+    ///
+    /// let errs: Vec<_> = ["1", "a", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .track_positions()
+    ///     .filter_map(Result::err)
+    ///     .map(|e| e.index())
+    ///     .collect();
+    ///
+    /// assert_eq!(errs, vec![1, 3]);
+    /// ```
+    fn track_positions(self) -> TrackPositions<Self>;
+}
+
+impl<I, O, E> Positions<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn track_positions(self) -> TrackPositions<Self> {
+        TrackPositions {
+            iter: self,
+            index: 0,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct TrackPositions<I> {
+    iter: I,
+    index: usize,
+}
+
+impl<I, O, E> Iterator for TrackPositions<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, Positioned<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        Some(r.map_err(|error| Positioned { index, error }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut index = self.index;
+        self.iter.fold(init, move |acc, r| {
+            let i = index;
+            index += 1;
+            g(acc, r.map_err(|error| Positioned { index: i, error }))
+        })
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for TrackPositions<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>> + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        let index = self.index + self.iter.len();
+        Some(r.map_err(|error| Positioned { index, error }))
+    }
+}
+
+impl<I, O, E> FusedIterator for TrackPositions<I> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_track_positions_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2), Err(3)];
+        let res: Vec<_> = v.into_iter().track_positions().rev().collect();
+
+        assert_eq!(
+            res,
+            vec![
+                Err(Positioned::new(2, 3)),
+                Ok(2),
+                Err(Positioned::new(0, 1))
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_positioned_forwards_source() {
+        let err = Positioned::new(0, "a".parse::<usize>().unwrap_err());
+
+        assert!(err.source().is_none());
+        assert_eq!(
+            err.to_string(),
+            "failed at item 0: invalid digit found in string"
+        );
+    }
+}
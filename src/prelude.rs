@@ -9,19 +9,78 @@
 //! Imports all things publicly, so you can `use resiter::prelude::*;` in your crate.
 //!
 
+#[cfg(feature = "alloc")]
+pub use aggregate::*;
 pub use and_then::*;
+pub use and_then_filter::*;
+pub use array_chunks::*;
+pub use batching::*;
+pub use capture::*;
+#[cfg(feature = "std")]
+pub use causes::*;
+#[cfg(feature = "alloc")]
+pub use collect::*;
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+pub use context::*;
+pub use convert::*;
+pub use count::*;
+pub use dedup::*;
+#[cfg(feature = "either")]
+pub use either_ext::*;
 pub use errors::*;
 pub use filter::*;
 pub use filter_map::*;
 pub use flat_map::*;
 pub use flatten::*;
+pub use fold::*;
+pub use for_each::*;
+pub use inspect::*;
+#[cfg(feature = "std")]
+pub use io::*;
+pub use lift::*;
 pub use map::*;
+pub use merge::*;
+pub use minmax::*;
 pub use ok_or_else::*;
 pub use oks::*;
 pub use onerr::*;
 pub use onok::*;
+pub use option::prelude::*;
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+pub use peek::*;
+pub use policy::*;
+pub use position::*;
+pub use query::*;
+pub use refs::*;
+#[cfg(feature = "alloc")]
+pub use report::*;
+pub use retry::*;
+pub use sink::*;
+pub use skip::*;
+#[cfg(feature = "alloc")]
+pub use split::*;
+pub use sum::*;
+pub use take::*;
+pub use tap::*;
+#[cfg(feature = "test-util")]
+pub use test_util::*;
+#[cfg(feature = "tracing")]
+pub use trace::*;
+pub use transpose::*;
 pub use try_filter::*;
 pub use try_filter_map::*;
 pub use try_map::*;
+#[cfg(feature = "try_trait")]
+pub use try_trait::*;
+#[cfg(feature = "std")]
+pub use unique::*;
+#[cfg(feature = "std")]
+pub use unwind::*;
 pub use unwrap::*;
+pub use unzip::*;
+pub use util::{GetErr, GetOk, Process};
+#[cfg(feature = "alloc")]
+pub use validated::*;
 pub use while_ok::*;
+pub use zip::*;
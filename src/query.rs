@@ -0,0 +1,371 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Consumers which answer questions about the `Ok` and `Err` items of an iterator
+//!
+//! All consumers only drain the iterator as far as needed to answer the question, so the rest of
+//! the iterator can still be used via `Iterator::by_ref`.
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to query the `Ok` and `Err` items
+pub trait Query<O, E>: Sized {
+    /// Check whether there is any `Err`, stopping at the first one
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    /// use std::str::FromStr;
+    ///
+    /// assert!(["1", "a", "3"].iter().map(|txt| usize::from_str(txt)).any_err());
+    /// assert!(!["1", "2", "3"].iter().map(|txt| usize::from_str(txt)).any_err());
+    /// ```
+    fn any_err(self) -> bool;
+
+    /// Check whether all items are `Ok`, stopping at the first `Err`
+    ///
+    /// This is `true` for an empty iterator.
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    /// use std::str::FromStr;
+    ///
+    /// assert!(["1", "2", "3"].iter().map(|txt| usize::from_str(txt)).all_ok());
+    /// assert!(!["1", "a", "3"].iter().map(|txt| usize::from_str(txt)).all_ok());
+    /// ```
+    fn all_ok(self) -> bool;
+
+    /// Return the first `Err`, discarding all `Ok` values before it
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    ///
+    /// let first = vec![Ok(1), Err("a"), Ok(2), Err("b")].into_iter().first_err();
+    /// assert_eq!(first, Some("a"));
+    ///
+    /// let first = vec![Ok::<_, &str>(1)].into_iter().first_err();
+    /// assert_eq!(first, None);
+    /// ```
+    fn first_err(self) -> Option<E>;
+
+    /// Return the first `Ok` value for which the predicate holds, stopping at the first `Err`
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    /// use std::str::FromStr;
+    ///
+    /// let found = ["1", "2", "a", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .find_ok(|i| i % 2 == 0);
+    /// assert_eq!(found, Ok(Some(2)));
+    ///
+    /// let found = ["1", "a", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .find_ok(|i| i % 2 == 0);
+    /// assert!(found.is_err());
+    /// ```
+    fn find_ok<P>(self, _: P) -> Result<Option<O>, E>
+    where
+        P: FnMut(&O) -> bool;
+
+    /// Return the first `Some` the closure returns for an `Ok` value, stopping at the first `Err`
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    ///
+    /// let found = vec![Ok::<_, ()>("a"), Ok("12"), Ok("3")]
+    ///     .into_iter()
+    ///     .find_map_ok(|s| s.parse::<usize>().ok());
+    ///
+    /// assert_eq!(found, Ok(Some(12)));
+    /// ```
+    fn find_map_ok<B, F>(self, _: F) -> Result<Option<B>, E>
+    where
+        F: FnMut(O) -> Option<B>;
+
+    /// Return the index of the first `Ok` value for which the predicate holds, stopping at the
+    /// first `Err`
+    ///
+    /// Only `Ok` values are counted, so the index is the position among all `Ok` values.
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    ///
+    /// let pos = vec![Ok(1), Ok(3), Ok(4)].into_iter().position_ok(|i: &usize| i % 2 == 0);
+    /// assert_eq!(pos, Ok::<_, ()>(Some(2)));
+    ///
+    /// let pos = vec![Ok(1), Err(()), Ok(4)].into_iter().position_ok(|i: &usize| i % 2 == 0);
+    /// assert_eq!(pos, Err(()));
+    /// ```
+    fn position_ok<P>(self, _: P) -> Result<Option<usize>, E>
+    where
+        P: FnMut(&O) -> bool;
+
+    /// Return the last `Ok` value, stopping at the first `Err`
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    /// use std::str::FromStr;
+    ///
+    /// let last = ["1", "2", "3"].iter().map(|txt| usize::from_str(txt)).last_ok();
+    /// assert_eq!(last, Ok(Some(3)));
+    ///
+    /// let last = ["1", "a", "3"].iter().map(|txt| usize::from_str(txt)).last_ok();
+    /// assert!(last.is_err());
+    /// ```
+    fn last_ok(self) -> Result<Option<O>, E>;
+
+    /// Return the last `Ok` value, ignoring all `Err` items
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    /// use std::str::FromStr;
+    ///
+    /// let last = ["1", "2", "a"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .last_ok_skipping_errs();
+    ///
+    /// assert_eq!(last, Some(2));
+    /// ```
+    fn last_ok_skipping_errs(self) -> Option<O>;
+
+    /// Return the `n`th `Ok` value, stopping at the first `Err`
+    ///
+    /// Only `Ok` values are counted and `n` is zero-based.
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    /// use std::str::FromStr;
+    ///
+    /// let nth = ["1", "2", "3"].iter().map(|txt| usize::from_str(txt)).nth_ok(1);
+    /// assert_eq!(nth, Ok(Some(2)));
+    ///
+    /// let nth = ["1", "a", "3"].iter().map(|txt| usize::from_str(txt)).nth_ok(1);
+    /// assert!(nth.is_err());
+    /// ```
+    fn nth_ok(self, n: usize) -> Result<Option<O>, E>;
+
+    /// Return the `n`th `Ok` value, ignoring all `Err` items
+    ///
+    /// Only `Ok` values are counted and `n` is zero-based.
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    /// use std::str::FromStr;
+    ///
+    /// let nth = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .nth_ok_skipping_errs(1);
+    ///
+    /// assert_eq!(nth, Some(3));
+    /// ```
+    fn nth_ok_skipping_errs(self, n: usize) -> Option<O>;
+
+    /// Check whether the `Ok` values are sorted in ascending order, stopping at the first `Err`
+    ///
+    /// The iterator is only drained up to the first pair of unsorted values, so an `Err` after
+    /// that pair is not reported. This is `Ok(true)` for an empty iterator.
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    /// use std::str::FromStr;
+    ///
+    /// let sorted = ["1", "2", "2", "5"].iter().map(|txt| usize::from_str(txt)).is_sorted_ok();
+    /// assert_eq!(sorted, Ok(true));
+    ///
+    /// let sorted = ["1", "3", "2", "a"].iter().map(|txt| usize::from_str(txt)).is_sorted_ok();
+    /// assert_eq!(sorted, Ok(false));
+    ///
+    /// let sorted = ["1", "a", "2"].iter().map(|txt| usize::from_str(txt)).is_sorted_ok();
+    /// assert!(sorted.is_err());
+    /// ```
+    // named after `Iterator::is_sorted`, which consumes the iterator as well
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_ok(self) -> Result<bool, E>
+    where
+        O: PartialOrd;
+
+    /// Check whether all `Ok` values are equal, stopping at the first `Err`
+    ///
+    /// The iterator is only drained up to the first value which differs from its predecessor, so
+    /// an `Err` after it is not reported. This is `Ok(true)` for an empty iterator.
+    ///
+    /// ```
+    /// use resiter::query::Query;
+    /// use std::str::FromStr;
+    ///
+    /// let equal = ["2", "2", "2"].iter().map(|txt| usize::from_str(txt)).all_equal_ok();
+    /// assert_eq!(equal, Ok(true));
+    ///
+    /// let equal = ["2", "3", "a"].iter().map(|txt| usize::from_str(txt)).all_equal_ok();
+    /// assert_eq!(equal, Ok(false));
+    ///
+    /// let equal = ["2", "a", "2"].iter().map(|txt| usize::from_str(txt)).all_equal_ok();
+    /// assert!(equal.is_err());
+    /// ```
+    fn all_equal_ok(self) -> Result<bool, E>
+    where
+        O: PartialEq;
+}
+
+impl<I, O, E> Query<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn any_err(mut self) -> bool {
+        self.any(|res| res.is_err())
+    }
+
+    #[inline]
+    fn all_ok(mut self) -> bool {
+        self.all(|res| res.is_ok())
+    }
+
+    #[inline]
+    fn first_err(mut self) -> Option<E> {
+        self.find_map(Result::err)
+    }
+
+    #[inline]
+    fn find_ok<P>(self, mut predicate: P) -> Result<Option<O>, E>
+    where
+        P: FnMut(&O) -> bool,
+    {
+        self.find_map_ok(|o| if predicate(&o) { Some(o) } else { None })
+    }
+
+    fn find_map_ok<B, F>(self, mut f: F) -> Result<Option<B>, E>
+    where
+        F: FnMut(O) -> Option<B>,
+    {
+        for res in self {
+            if let Some(b) = f(res?) {
+                return Ok(Some(b));
+            }
+        }
+        Ok(None)
+    }
+
+    fn position_ok<P>(self, mut predicate: P) -> Result<Option<usize>, E>
+    where
+        P: FnMut(&O) -> bool,
+    {
+        let mut index = 0;
+        self.find_map_ok(|o| {
+            if predicate(&o) {
+                Some(index)
+            } else {
+                index += 1;
+                None
+            }
+        })
+    }
+
+    fn last_ok(self) -> Result<Option<O>, E> {
+        let mut last = None;
+        for res in self {
+            last = Some(res?);
+        }
+        Ok(last)
+    }
+
+    #[inline]
+    fn last_ok_skipping_errs(self) -> Option<O> {
+        self.filter_map(Result::ok).last()
+    }
+
+    #[inline]
+    fn nth_ok(self, n: usize) -> Result<Option<O>, E> {
+        let mut remaining = n;
+        self.find_map_ok(|o| {
+            if remaining == 0 {
+                Some(o)
+            } else {
+                remaining -= 1;
+                None
+            }
+        })
+    }
+
+    #[inline]
+    fn nth_ok_skipping_errs(self, n: usize) -> Option<O> {
+        self.filter_map(Result::ok).nth(n)
+    }
+
+    #[inline]
+    fn is_sorted_ok(self) -> Result<bool, E>
+    where
+        O: PartialOrd,
+    {
+        all_pairs_ok(self, |prev, next| prev <= next)
+    }
+
+    #[inline]
+    fn all_equal_ok(self) -> Result<bool, E>
+    where
+        O: PartialEq,
+    {
+        all_pairs_ok(self, |prev, next| prev == next)
+    }
+}
+
+/// Check `predicate` for every pair of consecutive `Ok` values, stopping at the first `Err`
+fn all_pairs_ok<I, O, E, P>(iter: I, mut predicate: P) -> Result<bool, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+    P: FnMut(&O, &O) -> bool,
+{
+    let mut prev = None;
+    let violated = iter.find_map_ok(|o| {
+        if let Some(ref p) = prev {
+            if !predicate(p, &o) {
+                return Some(());
+            }
+        }
+        prev = Some(o);
+        None
+    })?;
+    Ok(violated.is_none())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_any_err_stops_at_first_error() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mut iter = v.into_iter();
+
+        assert!(iter.by_ref().any_err());
+        assert_eq!(iter.next(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn test_all_ok_empty() {
+        assert!(Vec::<Result<usize, usize>>::new().into_iter().all_ok());
+    }
+
+    #[test]
+    fn test_is_sorted_ok_stops_at_violation() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(0), Ok(2), Err(3)];
+        let mut iter = v.into_iter();
+
+        assert_eq!(iter.by_ref().is_sorted_ok(), Ok(false));
+        assert_eq!(iter.next(), Some(Ok(2)));
+    }
+
+    #[test]
+    fn test_find_ok_leaves_rest() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(2), Err(3)];
+        let mut iter = v.into_iter();
+
+        assert_eq!(iter.by_ref().find_ok(|i| *i == 2), Ok(Some(2)));
+        assert_eq!(iter.next(), Some(Err(3)));
+    }
+}
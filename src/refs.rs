@@ -0,0 +1,244 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::{FilterMap, FusedIterator, Map};
+use core::ops::Deref;
+
+/// Extension trait for `Iterator<Item = &Result<O, E>>`, e.g. when iterating over a stored
+/// `Vec<Result<O, E>>` by reference
+#[allow(clippy::type_complexity)]
+pub trait ResultRefs<'a, O: 'a, E: 'a>: Sized {
+    /// Turn every `&Result<O, E>` into a `Result<&O, &E>`
+    ///
+    /// This makes all extension traits for `Iterator<Item = Result<O, E>>` available without
+    /// cloning the items.
+    ///
+    /// ```
+    /// use resiter::map::Map;
+    /// use resiter::refs::ResultRefs;
+    ///
+    /// let results: Vec<Result<usize, String>> = vec![Ok(1), Err("a".to_string()), Ok(3)];
+    ///
+    /// let doubled: Vec<Result<usize, &String>> = results
+    ///     .iter()
+    ///     .result_refs()
+    ///     .map_ok(|i| i * 2)
+    ///     .collect();
+    ///
+    /// assert_eq!(doubled, vec![Ok(2), Err(&"a".to_string()), Ok(6)]);
+    /// ```
+    fn result_refs(self) -> Map<Self, fn(&'a Result<O, E>) -> Result<&'a O, &'a E>>;
+
+    /// Iterate over references to every `Ok` value while ignoring every `Err`
+    ///
+    /// ```
+    /// use resiter::refs::ResultRefs;
+    ///
+    /// let results: Vec<Result<usize, &str>> = vec![Ok(1), Err("a"), Ok(3)];
+    /// let oks: Vec<&usize> = results.iter().ok_refs().collect();
+    ///
+    /// assert_eq!(oks, vec![&1, &3]);
+    /// ```
+    fn ok_refs(self) -> FilterMap<Self, fn(&'a Result<O, E>) -> Option<&'a O>>;
+
+    /// Iterate over references to every `Err` value while ignoring every `Ok`
+    ///
+    /// ```
+    /// use resiter::refs::ResultRefs;
+    ///
+    /// let results: Vec<Result<usize, &str>> = vec![Ok(1), Err("a"), Ok(3)];
+    /// let errs: Vec<&&str> = results.iter().err_refs().collect();
+    ///
+    /// assert_eq!(errs, vec![&"a"]);
+    /// ```
+    fn err_refs(self) -> FilterMap<Self, fn(&'a Result<O, E>) -> Option<&'a E>>;
+
+    /// Turn every `&Result<O, E>` into a `Result<&T, &E>`, converting the `Ok` value via `AsRef`
+    ///
+    /// ```
+    /// use resiter::refs::ResultRefs;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let results: Vec<Result<PathBuf, &str>> = vec![Ok(PathBuf::from("/tmp")), Err("a")];
+    /// let paths: Vec<Result<&Path, &&str>> = results.iter().ok_as_ref().collect();
+    ///
+    /// assert_eq!(paths, vec![Ok(Path::new("/tmp")), Err(&"a")]);
+    /// ```
+    fn ok_as_ref<T>(self) -> Map<Self, fn(&'a Result<O, E>) -> Result<&'a T, &'a E>>
+    where
+        O: AsRef<T>,
+        T: ?Sized + 'a;
+
+    /// Turn every `&Result<O, E>` into a `Result<&O::Target, &E>`, like `Result::as_deref`
+    ///
+    /// ```
+    /// use resiter::refs::ResultRefs;
+    ///
+    /// let results: Vec<Result<String, &str>> = vec![Ok("a".to_string()), Err("b")];
+    /// let strs: Vec<Result<&str, &&str>> = results.iter().ok_as_deref().collect();
+    ///
+    /// assert_eq!(strs, vec![Ok("a"), Err(&"b")]);
+    /// ```
+    fn ok_as_deref(self) -> Map<Self, fn(&'a Result<O, E>) -> Result<&'a O::Target, &'a E>>
+    where
+        O: Deref;
+
+    /// Filter `Ok` items by a predicate while passing all `Err` items through, keeping the items
+    /// as references
+    ///
+    /// ```
+    /// use resiter::refs::ResultRefs;
+    ///
+    /// let results: Vec<Result<usize, &str>> = vec![Ok(1), Err("a"), Ok(4)];
+    /// let filtered: Vec<&Result<usize, &str>> =
+    ///     results.iter().filter_ok_ref(|i| i % 2 == 0).collect();
+    ///
+    /// assert_eq!(filtered, vec![&Err("a"), &Ok(4)]);
+    /// ```
+    fn filter_ok_ref<P>(self, _: P) -> FilterOkRef<Self, P>
+    where
+        P: FnMut(&O) -> bool;
+}
+
+impl<'a, I, O: 'a, E: 'a> ResultRefs<'a, O, E> for I
+where
+    I: Iterator<Item = &'a Result<O, E>> + Sized,
+{
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn result_refs(self) -> Map<Self, fn(&'a Result<O, E>) -> Result<&'a O, &'a E>> {
+        self.map(Result::as_ref)
+    }
+
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn ok_refs(self) -> FilterMap<Self, fn(&'a Result<O, E>) -> Option<&'a O>> {
+        self.filter_map(|r| r.as_ref().ok())
+    }
+
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn err_refs(self) -> FilterMap<Self, fn(&'a Result<O, E>) -> Option<&'a E>> {
+        self.filter_map(|r| r.as_ref().err())
+    }
+
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn ok_as_ref<T>(self) -> Map<Self, fn(&'a Result<O, E>) -> Result<&'a T, &'a E>>
+    where
+        O: AsRef<T>,
+        T: ?Sized + 'a,
+    {
+        self.map(|r| r.as_ref().map(AsRef::as_ref))
+    }
+
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn ok_as_deref(self) -> Map<Self, fn(&'a Result<O, E>) -> Result<&'a O::Target, &'a E>>
+    where
+        O: Deref,
+    {
+        self.map(|r| r.as_ref().map(Deref::deref))
+    }
+
+    #[inline]
+    fn filter_ok_ref<P>(self, predicate: P) -> FilterOkRef<Self, P>
+    where
+        P: FnMut(&O) -> bool,
+    {
+        FilterOkRef {
+            iter: self,
+            predicate,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct FilterOkRef<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> fmt::Debug for FilterOkRef<I, P>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterOkRef")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, I, O: 'a, E: 'a, P> Iterator for FilterOkRef<I, P>
+where
+    I: Iterator<Item = &'a Result<O, E>>,
+    P: FnMut(&O) -> bool,
+{
+    type Item = &'a Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.iter.find(|r| match **r {
+            Ok(ref o) => predicate(o),
+            Err(_) => true,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut predicate = self.predicate;
+        self.iter.fold(init, move |acc, r| match *r {
+            Ok(ref o) if !predicate(o) => acc,
+            _ => g(acc, r),
+        })
+    }
+}
+
+impl<'a, I, O: 'a, E: 'a, P> DoubleEndedIterator for FilterOkRef<I, P>
+where
+    I: DoubleEndedIterator<Item = &'a Result<O, E>>,
+    P: FnMut(&O) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.iter.rfind(|r| match **r {
+            Ok(ref o) => predicate(o),
+            Err(_) => true,
+        })
+    }
+}
+
+impl<'a, I, O: 'a, E: 'a, P> FusedIterator for FilterOkRef<I, P>
+where
+    I: FusedIterator<Item = &'a Result<O, E>>,
+    P: FnMut(&O) -> bool,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_ok_ref_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(4), Ok(6)];
+        let res: Vec<_> = v.iter().filter_ok_ref(|i| *i > 1).rev().collect();
+
+        assert_eq!(res, vec![&Ok(6), &Ok(4), &Err(2)]);
+    }
+}
@@ -0,0 +1,170 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! A collector which records every error together with its position
+//!
+//! Only available with the `alloc` feature.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FromIterator;
+
+use count::ResultCounts;
+
+/// Report of all errors of an `Iterator<Item = Result<O, E>>`, together with their index
+///
+/// `Ok` values are only counted and then dropped. The `Display` implementation renders a
+/// human-readable summary.
+///
+/// ```
+/// use resiter::report::ErrorReport;
+/// use std::str::FromStr;
+///
+/// let report: ErrorReport<_> = ["1", "a", "3", "b"]
+///     .iter()
+///     .map(|txt| usize::from_str(txt))
+///     .collect();
+///
+/// assert_eq!(report.err_count(), 2);
+/// assert_eq!(report.total(), 4);
+/// assert_eq!(report.errors()[1].0, 3);
+/// assert_eq!(
+///     report.to_string(),
+///     "2 of 4 items failed: #1 invalid digit found in string, #3 invalid digit found in string"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorReport<E> {
+    errors: Vec<(usize, E)>,
+    total: usize,
+}
+
+impl<E> ErrorReport<E> {
+    /// Create an empty report
+    #[inline]
+    pub fn new() -> Self {
+        ErrorReport {
+            errors: Vec::new(),
+            total: 0,
+        }
+    }
+
+    /// All recorded errors together with the index of the item they were found at
+    #[inline]
+    pub fn errors(&self) -> &[(usize, E)] {
+        &self.errors
+    }
+
+    /// Consume the report and return the recorded errors together with their index
+    #[inline]
+    pub fn into_errors(self) -> Vec<(usize, E)> {
+        self.errors
+    }
+
+    /// Total number of items
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Number of `Err` items
+    #[inline]
+    pub fn err_count(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Number of `Ok` items
+    #[inline]
+    pub fn ok_count(&self) -> usize {
+        self.total - self.errors.len()
+    }
+
+    /// Number of `Ok` and `Err` items
+    #[inline]
+    pub fn counts(&self) -> ResultCounts {
+        ResultCounts {
+            oks: self.ok_count(),
+            errs: self.err_count(),
+        }
+    }
+
+    /// Whether no errors were recorded
+    #[inline]
+    pub fn is_all_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl<E> Default for ErrorReport<E> {
+    #[inline]
+    fn default() -> Self {
+        ErrorReport::new()
+    }
+}
+
+impl<O, E> Extend<Result<O, E>> for ErrorReport<E> {
+    /// Record the errors of `iter`, continuing the item indices of this report
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = Result<O, E>>,
+    {
+        for r in iter {
+            if let Err(e) = r {
+                self.errors.push((self.total, e));
+            }
+            self.total += 1;
+        }
+    }
+}
+
+impl<O, E> FromIterator<Result<O, E>> for ErrorReport<E> {
+    #[inline]
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = Result<O, E>>,
+    {
+        let mut report = ErrorReport::new();
+        report.extend(iter);
+        report
+    }
+}
+
+impl<E> fmt::Display for ErrorReport<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} of {} items failed", self.err_count(), self.total)?;
+        for (n, &(index, ref e)) in self.errors.iter().enumerate() {
+            let sep = if n == 0 { ": " } else { ", " };
+            write!(f, "{}#{} {}", sep, index, e)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn test_error_report_extend_continues_indices() {
+        let mut report: ErrorReport<&str> = vec![Ok(1), Err("a")].into_iter().collect();
+        report.extend(vec![Err("b"), Ok(2)]);
+
+        assert_eq!(report.errors(), &[(1, "a"), (2, "b")]);
+        assert_eq!(report.counts(), ResultCounts { oks: 2, errs: 2 });
+    }
+
+    #[test]
+    fn test_error_report_display_all_ok() {
+        let report: ErrorReport<&str> = vec![Ok(1), Ok(2)].into_iter().collect();
+
+        assert!(report.is_all_ok());
+        assert_eq!(report.to_string(), "0 of 2 items failed");
+    }
+}
@@ -0,0 +1,186 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to retry failed items
+pub trait Retry<O, E>: Sized {
+    /// Try to regenerate each `Err` item up to `retries` times
+    ///
+    /// For every `Err`, the closure is called with the error. If it returns `Some(Ok(_))`, that
+    /// value is yielded. If it returns `Some(Err(_))`, the new error is retried, until `retries`
+    /// attempts were made. If it returns `None`, it gives up on the item. Either way, the last
+    /// error is yielded if no attempt succeeded.
+    ///
+    /// ```
+    /// use resiter::retry::Retry;
+    ///
+    /// let mut attempts = 0;
+    /// let res: Vec<Result<usize, usize>> = vec![Ok(1), Err(0), Ok(3)]
+    ///     .into_iter()
+    ///     .retry_errs(3, |e| {
+    ///         attempts += 1;
+    ///         if *e < 2 {
+    ///             Some(Err(e + 1))
+    ///         } else {
+    ///             Some(Ok(2))
+    ///         }
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(res, vec![Ok(1), Ok(2), Ok(3)]);
+    /// assert_eq!(attempts, 3);
+    /// ```
+    ///
+    /// When all attempts fail, the last error is yielded:
+    ///
+    /// ```
+    /// use resiter::retry::Retry;
+    ///
+    /// let res: Vec<Result<usize, usize>> = vec![Err(0)]
+    ///     .into_iter()
+    ///     .retry_errs(2, |e| Some(Err(e + 1)))
+    ///     .collect();
+    ///
+    /// assert_eq!(res, vec![Err(2)]);
+    /// ```
+    fn retry_errs<F>(self, retries: usize, _: F) -> RetryErrs<Self, F>
+    where
+        F: FnMut(&E) -> Option<Result<O, E>>;
+}
+
+impl<I, O, E> Retry<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn retry_errs<F>(self, retries: usize, f: F) -> RetryErrs<Self, F>
+    where
+        F: FnMut(&E) -> Option<Result<O, E>>,
+    {
+        RetryErrs {
+            iter: self,
+            f,
+            retries,
+        }
+    }
+}
+
+#[inline]
+fn retry<O, E, F>(r: Result<O, E>, retries: usize, f: &mut F) -> Result<O, E>
+where
+    F: FnMut(&E) -> Option<Result<O, E>>,
+{
+    let mut e = match r {
+        Ok(o) => return Ok(o),
+        Err(e) => e,
+    };
+    for _ in 0..retries {
+        match f(&e) {
+            Some(Ok(o)) => return Ok(o),
+            Some(Err(next)) => e = next,
+            None => break,
+        }
+    }
+    Err(e)
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RetryErrs<I, F> {
+    iter: I,
+    f: F,
+    retries: usize,
+}
+
+impl<I, F> fmt::Debug for RetryErrs<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryErrs")
+            .field("iter", &self.iter)
+            .field("retries", &self.retries)
+            .finish()
+    }
+}
+
+impl<I, O, E, F> Iterator for RetryErrs<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Option<Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        Some(retry(r, self.retries, &mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let retries = self.retries;
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, r| g(acc, retry(r, retries, &mut f)))
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for RetryErrs<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Option<Result<O, E>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        Some(retry(r, self.retries, &mut self.f))
+    }
+}
+
+impl<I, O, E, F> FusedIterator for RetryErrs<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Option<Result<O, E>>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_errs_gives_up_on_none() {
+        let mut attempts = 0;
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2)];
+        let res: Vec<_> = v
+            .into_iter()
+            .retry_errs(5, |_| {
+                attempts += 1;
+                None
+            })
+            .collect();
+
+        assert_eq!(res, vec![Err(1), Ok(2)]);
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_retry_errs_zero_retries() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1)];
+        let res: Vec<_> = v.into_iter().retry_errs(0, |_| Some(Ok(0))).collect();
+
+        assert_eq!(res, vec![Err(1)]);
+    }
+}
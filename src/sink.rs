@@ -0,0 +1,279 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+#[cfg(feature = "std")]
+use std::sync::mpsc::Sender;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to divert all errors into a sink
+pub trait ErrorSink<O, E>: Sized {
+    /// Push every `Err` item into `sink` and yield only the `Ok` values
+    ///
+    /// ```
+    /// use resiter::sink::ErrorSink;
+    /// use std::str::FromStr;
+    ///
+    /// let mut errors = Vec::new();
+    /// let sum: usize = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .errors_to(&mut errors)
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 7);
+    /// assert_eq!(errors.len(), 2);
+    /// ```
+    fn errors_to<X>(self, sink: &mut X) -> ErrorsTo<'_, Self, X>
+    where
+        X: Extend<E>;
+
+    /// Send every `Err` item through `sender` and yield only the `Ok` values
+    ///
+    /// Errors are silently dropped once the receiving end hung up. Only available with the `std`
+    /// feature.
+    ///
+    /// ```
+    /// use resiter::sink::ErrorSink;
+    /// use std::str::FromStr;
+    /// use std::sync::mpsc::channel;
+    /// use std::thread;
+    ///
+    /// let (sender, receiver) = channel();
+    /// let reporter = thread::spawn(move || receiver.iter().count());
+    ///
+    /// let sum: usize = ["1", "2", "a", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .errors_to_sender(sender)
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 7);
+    /// assert_eq!(reporter.join().unwrap(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    fn errors_to_sender(self, sender: Sender<E>) -> ErrorsToSender<Self, E>;
+}
+
+impl<I, O, E> ErrorSink<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn errors_to<X>(self, sink: &mut X) -> ErrorsTo<'_, Self, X>
+    where
+        X: Extend<E>,
+    {
+        ErrorsTo { iter: self, sink }
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn errors_to_sender(self, sender: Sender<E>) -> ErrorsToSender<Self, E> {
+        ErrorsToSender { iter: self, sender }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ErrorsTo<'a, I, X: 'a> {
+    iter: I,
+    sink: &'a mut X,
+}
+
+impl<'a, I, X> fmt::Debug for ErrorsTo<'a, I, X>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ErrorsTo")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, I, O, E, X> Iterator for ErrorsTo<'a, I, X>
+where
+    I: Iterator<Item = Result<O, E>>,
+    X: Extend<E>,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(o) => return Some(o),
+                Err(e) => self.sink.extend(Some(e)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let sink = self.sink;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(o) => g(acc, o),
+            Err(e) => {
+                sink.extend(Some(e));
+                acc
+            }
+        })
+    }
+}
+
+impl<'a, I, O, E, X> DoubleEndedIterator for ErrorsTo<'a, I, X>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    X: Extend<E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back()? {
+                Ok(o) => return Some(o),
+                Err(e) => self.sink.extend(Some(e)),
+            }
+        }
+    }
+}
+
+impl<'a, I, O, E, X> FusedIterator for ErrorsTo<'a, I, X>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    X: Extend<E>,
+{
+}
+
+#[cfg(feature = "std")]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ErrorsToSender<I, E> {
+    iter: I,
+    sender: Sender<E>,
+}
+
+#[cfg(feature = "std")]
+impl<I, E> Clone for ErrorsToSender<I, E>
+where
+    I: Clone,
+{
+    fn clone(&self) -> Self {
+        ErrorsToSender {
+            iter: self.iter.clone(),
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I, E> fmt::Debug for ErrorsToSender<I, E>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ErrorsToSender")
+            .field("iter", &self.iter)
+            .field("sender", &self.sender)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I, O, E> Iterator for ErrorsToSender<I, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(o) => return Some(o),
+                Err(e) => {
+                    let _ = self.sender.send(e);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let sender = self.sender;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(o) => g(acc, o),
+            Err(e) => {
+                let _ = sender.send(e);
+                acc
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I, O, E> DoubleEndedIterator for ErrorsToSender<I, E>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back()? {
+                Ok(o) => return Some(o),
+                Err(e) => {
+                    let _ = self.sender.send(e);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I, O, E> FusedIterator for ErrorsToSender<I, E> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors_to_partial_consumption() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2), Err(3), Ok(4)];
+        let mut errors = Vec::new();
+
+        {
+            let mut iter = v.into_iter().errors_to(&mut errors);
+            assert_eq!(iter.next(), Some(2));
+        }
+
+        assert_eq!(errors, vec![1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_errors_to_sender_receiver_dropped() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        drop(receiver);
+
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2), Err(3)];
+        let oks: Vec<_> = v.into_iter().errors_to_sender(sender).collect();
+
+        assert_eq!(oks, vec![2]);
+    }
+}
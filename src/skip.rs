@@ -0,0 +1,259 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to skip items depending on the `Ok` values
+pub trait Skip<O, E>: Sized {
+    /// Skip leading `Ok` items while the predicate holds, never dropping `Err` items.
+    ///
+    /// Once the predicate returned `false` for an `Ok` item, it is not called anymore and all
+    /// following items are yielded.
+    ///
+    /// ```
+    /// use resiter::skip::Skip;
+    /// use std::str::FromStr;
+    ///
+    /// let rest: Vec<_> = ["1", "a", "2", "10", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .skip_while_ok(|i| *i < 5)
+    ///     .collect();
+    ///
+    /// assert_eq!(rest.len(), 4);
+    /// assert!(rest[0].is_err());
+    /// assert_eq!(rest[1], Ok(10));
+    /// assert_eq!(rest[2], Ok(3));
+    /// assert!(rest[3].is_err());
+    /// ```
+    fn skip_while_ok<P>(self, _: P) -> SkipWhileOk<Self, P>
+    where
+        P: FnMut(&O) -> bool;
+
+    /// Skip the first `n` `Ok` items, never dropping `Err` items.
+    ///
+    /// Other than `Iterator::skip`, errors do not count toward `n` and are yielded even if they
+    /// are found between skipped values.
+    ///
+    /// ```
+    /// use resiter::skip::Skip;
+    /// use std::str::FromStr;
+    ///
+    /// let rest: Vec<_> = ["1", "a", "2", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .skip_oks(2)
+    ///     .collect();
+    ///
+    /// assert_eq!(rest.len(), 3);
+    /// assert!(rest[0].is_err());
+    /// assert_eq!(rest[1], Ok(3));
+    /// assert!(rest[2].is_err());
+    /// ```
+    fn skip_oks(self, n: usize) -> SkipOks<Self>;
+}
+
+impl<I, O, E> Skip<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn skip_while_ok<P>(self, predicate: P) -> SkipWhileOk<Self, P>
+    where
+        P: FnMut(&O) -> bool,
+    {
+        SkipWhileOk {
+            iter: self,
+            predicate,
+            skipping: true,
+        }
+    }
+
+    #[inline]
+    fn skip_oks(self, n: usize) -> SkipOks<Self> {
+        SkipOks {
+            iter: self,
+            remaining: n,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SkipWhileOk<I, P> {
+    iter: I,
+    predicate: P,
+    skipping: bool,
+}
+
+impl<I, P> fmt::Debug for SkipWhileOk<I, P>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SkipWhileOk")
+            .field("iter", &self.iter)
+            .field("skipping", &self.skipping)
+            .finish()
+    }
+}
+
+impl<I, O, E, P> Iterator for SkipWhileOk<I, P>
+where
+    I: Iterator<Item = Result<O, E>>,
+    P: FnMut(&O) -> bool,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.skipping {
+            return self.iter.next();
+        }
+
+        loop {
+            match self.iter.next() {
+                Some(Ok(x)) => {
+                    if !(self.predicate)(&x) {
+                        self.skipping = false;
+                        return Some(Ok(x));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.skipping {
+            (0, self.iter.size_hint().1)
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut skipping = self.skipping;
+        let mut predicate = self.predicate;
+        self.iter.fold(init, move |acc, r| {
+            if skipping {
+                if let Ok(ref x) = r {
+                    if predicate(x) {
+                        return acc;
+                    }
+                    skipping = false;
+                }
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, P> FusedIterator for SkipWhileOk<I, P>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    P: FnMut(&O) -> bool,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct SkipOks<I> {
+    iter: I,
+    /// Number of `Ok` values which still have to be skipped
+    remaining: usize,
+}
+
+impl<I, O, E> Iterator for SkipOks<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            match self.iter.next()? {
+                Ok(_) => self.remaining -= 1,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (lo.saturating_sub(self.remaining), hi)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut remaining = self.remaining;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(_) if remaining > 0 => {
+                remaining -= 1;
+                acc
+            }
+            r => g(acc, r),
+        })
+    }
+}
+
+impl<I, O, E> FusedIterator for SkipOks<I> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skip_while_ok_fold() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(1)];
+        let sum = v
+            .into_iter()
+            .skip_while_ok(|i| *i < 3)
+            .fold(0, |acc, r| match r {
+                Ok(i) => acc + i,
+                Err(e) => acc + 100 * e,
+            });
+
+        assert_eq!(sum, 204);
+    }
+
+    #[test]
+    fn test_skip_while_ok_fold_keeps_skipping_after_err() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(1), Ok(3)];
+        let collected: Vec<_> = v.clone().into_iter().skip_while_ok(|i| *i < 3).collect();
+        let mut folded = Vec::new();
+        v.into_iter()
+            .skip_while_ok(|i| *i < 3)
+            .for_each(|r| folded.push(r));
+
+        assert_eq!(collected, vec![Err(2), Ok(3)]);
+        assert_eq!(folded, collected);
+    }
+
+    #[test]
+    fn test_skip_oks_fold_after_next() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2), Err(3), Ok(4), Ok(5)];
+        let mut iter = v.into_iter().skip_oks(2);
+
+        assert_eq!(iter.next(), Some(Err(1)));
+        let rest = iter.fold(Vec::new(), |mut acc, r| {
+            acc.push(r);
+            acc
+        });
+
+        assert_eq!(rest, vec![Err(3), Ok(5)]);
+    }
+}
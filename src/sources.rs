@@ -0,0 +1,257 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Constructors for `Iterator<Item = Result<O, E>>` from fallible closures
+
+use core::fmt;
+use core::iter::{self, FusedIterator, Once};
+
+/// Create an iterator from a fallible closure which signals the end with `Ok(None)`
+///
+/// Errors are yielded and the closure is called again afterwards. Once the closure returned
+/// `Ok(None)`, it is not called anymore.
+///
+/// ```
+/// use resiter::sources::from_fn_result;
+///
+/// let mut count = 0;
+/// let items: Vec<Result<usize, &str>> = from_fn_result(|| {
+///     count += 1;
+///     match count {
+///         2 => Err("flaky"),
+///         1..=3 => Ok(Some(count)),
+///         _ => Ok(None),
+///     }
+/// })
+/// .collect();
+///
+/// assert_eq!(items, vec![Ok(1), Err("flaky"), Ok(3)]);
+/// ```
+#[inline]
+pub fn from_fn_result<O, E, F>(f: F) -> FromFnResult<F>
+where
+    F: FnMut() -> Result<Option<O>, E>,
+{
+    FromFnResult { f, done: false }
+}
+
+/// Create an infinite iterator by calling a fallible closure over and over again
+///
+/// ```
+/// use resiter::sources::repeat_with_result;
+///
+/// let mut n = 0;
+/// let items: Vec<Result<usize, usize>> = repeat_with_result(|| {
+///     n += 1;
+///     if n % 2 == 0 { Err(n) } else { Ok(n) }
+/// })
+/// .take(4)
+/// .collect();
+///
+/// assert_eq!(items, vec![Ok(1), Err(2), Ok(3), Err(4)]);
+/// ```
+#[inline]
+pub fn repeat_with_result<O, E, F>(f: F) -> RepeatWithResult<F>
+where
+    F: FnMut() -> Result<O, E>,
+{
+    RepeatWithResult { f }
+}
+
+/// Create an iterator where each item is computed from the previous one by a fallible function
+///
+/// The iterator starts with `first` and stops when the successor function returns `Ok(None)`.
+/// If it returns an `Err`, that error is yielded and the iterator stops, as there is no value to
+/// continue from.
+///
+/// ```
+/// use resiter::sources::successors_result;
+///
+/// let powers: Vec<Result<u8, &str>> = successors_result(Some(1u8), |n| {
+///     n.checked_mul(4).map(Some).ok_or("overflow")
+/// })
+/// .collect();
+///
+/// assert_eq!(
+///     powers,
+///     vec![Ok(1), Ok(4), Ok(16), Ok(64), Err("overflow")]
+/// );
+/// ```
+#[inline]
+pub fn successors_result<O, E, F>(first: Option<O>, succ: F) -> SuccessorsResult<O, E, F>
+where
+    F: FnMut(&O) -> Result<Option<O>, E>,
+{
+    SuccessorsResult {
+        next: first.map(Ok),
+        succ,
+    }
+}
+
+/// Create an iterator which yields a single `Err`
+///
+/// ```
+/// use resiter::sources::once_err;
+///
+/// let items: Vec<Result<usize, &str>> = vec![Ok(1)].into_iter().chain(once_err("end")).collect();
+///
+/// assert_eq!(items, vec![Ok(1), Err("end")]);
+/// ```
+#[inline]
+pub fn once_err<O, E>(e: E) -> Once<Result<O, E>> {
+    iter::once(Err(e))
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct FromFnResult<F> {
+    f: F,
+    done: bool,
+}
+
+impl<F> fmt::Debug for FromFnResult<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FromFnResult")
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<O, E, F> Iterator for FromFnResult<F>
+where
+    F: FnMut() -> Result<Option<O>, E>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match (self.f)() {
+            Ok(Some(o)) => Some(Ok(o)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+impl<O, E, F> FusedIterator for FromFnResult<F> where F: FnMut() -> Result<Option<O>, E> {}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct RepeatWithResult<F> {
+    f: F,
+}
+
+impl<F> fmt::Debug for RepeatWithResult<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RepeatWithResult").finish()
+    }
+}
+
+impl<O, E, F> Iterator for RepeatWithResult<F>
+where
+    F: FnMut() -> Result<O, E>,
+{
+    type Item = Result<O, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.f)())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<O, E, F> FusedIterator for RepeatWithResult<F> where F: FnMut() -> Result<O, E> {}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SuccessorsResult<O, E, F> {
+    next: Option<Result<O, E>>,
+    succ: F,
+}
+
+impl<O, E, F> fmt::Debug for SuccessorsResult<O, E, F>
+where
+    O: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SuccessorsResult")
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+impl<O, E, F> Iterator for SuccessorsResult<O, E, F>
+where
+    F: FnMut(&O) -> Result<Option<O>, E>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.next.take()?;
+        if let Ok(ref o) = item {
+            self.next = (self.succ)(o).transpose();
+        }
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(Ok(_)) => (1, None),
+            Some(Err(_)) => (1, Some(1)),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<O, E, F> FusedIterator for SuccessorsResult<O, E, F> where F: FnMut(&O) -> Result<Option<O>, E> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_successors_result_empty() {
+        let mut iter = successors_result(None, |n: &usize| Ok::<_, ()>(Some(n + 1)));
+
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_from_fn_result_fuses() {
+        let mut calls = 0;
+        let mut iter = from_fn_result(|| {
+            calls += 1;
+            Ok::<Option<usize>, ()>(None)
+        });
+
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        drop(iter);
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,198 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Split an iterator over `Result`s into independent iterators over the `Ok` and `Err` values
+//!
+//! Only available with the `alloc` feature.
+
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to split it into two iterators
+pub trait SplitResults<O, E>: Sized {
+    /// Split the iterator into one iterator over the `Ok` values and one over the `Err` values
+    ///
+    /// Both iterators share the underlying iterator and can be consumed independently. Items
+    /// which are pulled from the underlying iterator by one side are buffered until the other
+    /// side consumes them, so consuming one side completely before the other buffers all items
+    /// of the other side.
+    ///
+    /// ```
+    /// use resiter::split::SplitResults;
+    /// use std::str::FromStr;
+    ///
+    /// let (oks, errs) = ["1", "a", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .split_results();
+    ///
+    /// assert_eq!(errs.count(), 2);
+    /// assert_eq!(oks.collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    fn split_results(self) -> (SplitOks<Self, O, E>, SplitErrs<Self, O, E>);
+}
+
+impl<I, O, E> SplitResults<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    fn split_results(self) -> (SplitOks<Self, O, E>, SplitErrs<Self, O, E>) {
+        let shared = Rc::new(RefCell::new(Shared {
+            iter: self,
+            oks: VecDeque::new(),
+            errs: VecDeque::new(),
+        }));
+        (
+            SplitOks {
+                shared: shared.clone(),
+            },
+            SplitErrs { shared },
+        )
+    }
+}
+
+/// State shared between [`SplitOks`] and [`SplitErrs`]
+#[derive(Debug)]
+struct Shared<I, O, E> {
+    iter: I,
+    /// `Ok` values pulled by `SplitErrs`, but not yet consumed by `SplitOks`
+    oks: VecDeque<O>,
+    /// `Err` values pulled by `SplitOks`, but not yet consumed by `SplitErrs`
+    errs: VecDeque<E>,
+}
+
+impl<I, O, E> Shared<I, O, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    fn next_ok(&mut self) -> Option<O> {
+        if let Some(o) = self.oks.pop_front() {
+            return Some(o);
+        }
+        loop {
+            match self.iter.next()? {
+                Ok(o) => return Some(o),
+                Err(e) => self.errs.push_back(e),
+            }
+        }
+    }
+
+    fn next_err(&mut self) -> Option<E> {
+        if let Some(e) = self.errs.pop_front() {
+            return Some(e);
+        }
+        loop {
+            match self.iter.next()? {
+                Ok(o) => self.oks.push_back(o),
+                Err(e) => return Some(e),
+            }
+        }
+    }
+}
+
+/// The `Ok` side of [`SplitResults::split_results`]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct SplitOks<I, O, E> {
+    shared: Rc<RefCell<Shared<I, O, E>>>,
+}
+
+impl<I, O, E> fmt::Debug for SplitOks<I, O, E>
+where
+    I: fmt::Debug,
+    O: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SplitOks")
+            .field("shared", &self.shared)
+            .finish()
+    }
+}
+
+impl<I, O, E> Iterator for SplitOks<I, O, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = O;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.shared.borrow_mut().next_ok()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let shared = self.shared.borrow();
+        let buffered = shared.oks.len();
+        let hi = shared.iter.size_hint().1;
+        (buffered, hi.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+impl<I, O, E> FusedIterator for SplitOks<I, O, E> where I: FusedIterator<Item = Result<O, E>> {}
+
+/// The `Err` side of [`SplitResults::split_results`]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct SplitErrs<I, O, E> {
+    shared: Rc<RefCell<Shared<I, O, E>>>,
+}
+
+impl<I, O, E> fmt::Debug for SplitErrs<I, O, E>
+where
+    I: fmt::Debug,
+    O: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SplitErrs")
+            .field("shared", &self.shared)
+            .finish()
+    }
+}
+
+impl<I, O, E> Iterator for SplitErrs<I, O, E>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = E;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.shared.borrow_mut().next_err()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let shared = self.shared.borrow();
+        let buffered = shared.errs.len();
+        let hi = shared.iter.size_hint().1;
+        (buffered, hi.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+impl<I, O, E> FusedIterator for SplitErrs<I, O, E> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_results_interleaved() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3), Ok(4), Err(5)];
+        let (mut oks, mut errs) = v.into_iter().split_results();
+
+        assert_eq!(errs.next(), Some(2));
+        assert_eq!(oks.size_hint(), (1, Some(4)));
+        assert_eq!(oks.next(), Some(1));
+        assert_eq!(oks.next(), Some(4));
+        assert_eq!(errs.size_hint(), (1, Some(2)));
+        assert_eq!(errs.collect::<Vec<_>>(), vec![3, 5]);
+        assert_eq!(oks.next(), None);
+    }
+}
@@ -0,0 +1,162 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Extension trait for `Stream<Item = Result<O, E>>` to selectively transform Oks and Errors.
+pub trait StreamAndThen<O, E>: Sized {
+    /// Map oks selectively, possibly converting them to errors
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use resiter::stream::and_then::StreamAndThen;
+    ///
+    /// let mapped: Vec<Result<usize, &str>> = block_on(
+    ///     stream::iter(vec![Ok(1), Err("a"), Ok(3)])
+    ///         .and_then_ok(|i| if i < 2 { Ok(2 * i) } else { Err("too high") })
+    ///         .collect(),
+    /// );
+    ///
+    /// assert_eq!(mapped, vec![Ok(2), Err("a"), Err("too high")]);
+    /// ```
+    fn and_then_ok<F, O2>(self, _: F) -> AndThenOk<Self, F>
+    where
+        F: FnMut(O) -> Result<O2, E>;
+
+    /// Map errors selectively, possibly converting them to Oks
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use resiter::stream::and_then::StreamAndThen;
+    ///
+    /// let mapped: Vec<Result<usize, usize>> = block_on(
+    ///     stream::iter(vec![Ok(1), Err("a"), Err("bb")])
+    ///         .and_then_err(|e| if e == "a" { Ok(15) } else { Err(e.len()) })
+    ///         .collect(),
+    /// );
+    ///
+    /// assert_eq!(mapped, vec![Ok(1), Ok(15), Err(2)]);
+    /// ```
+    fn and_then_err<F, E2>(self, _: F) -> AndThenErr<Self, F>
+    where
+        F: FnMut(E) -> Result<O, E2>;
+}
+
+impl<S, O, E> StreamAndThen<O, E> for S
+where
+    S: Stream<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn and_then_ok<F, O2>(self, f: F) -> AndThenOk<Self, F>
+    where
+        F: FnMut(O) -> Result<O2, E>,
+    {
+        AndThenOk { stream: self, f }
+    }
+
+    #[inline]
+    fn and_then_err<F, E2>(self, f: F) -> AndThenErr<Self, F>
+    where
+        F: FnMut(E) -> Result<O, E2>,
+    {
+        AndThenErr { stream: self, f }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Clone)]
+    pub struct AndThenOk<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F> fmt::Debug for AndThenOk<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AndThenOk")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S, O, E, O2, F> Stream for AndThenOk<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+{
+    type Item = Result<O2, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let stream = this.stream;
+        let f = this.f;
+        stream.poll_next(cx).map(|o| o.map(|r| r.and_then(f)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Clone)]
+    pub struct AndThenErr<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F> fmt::Debug for AndThenErr<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AndThenErr")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S, O, E, E2, F> Stream for AndThenErr<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(E) -> Result<O, E2>,
+{
+    type Item = Result<O, E2>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let stream = this.stream;
+        let f = this.f;
+        stream.poll_next(cx).map(|o| o.map(|r| r.or_else(f)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,178 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Extension trait for `Stream<Item = Result<O, E>>` to filter one kind of result (and leaving the other as is)
+pub trait StreamFilter<O, E>: Sized {
+    /// Filter `Ok` items while keeping `Err`
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use resiter::stream::filter::StreamFilter;
+    ///
+    /// let filtered: Vec<Result<usize, &str>> = block_on(
+    ///     stream::iter(vec![Ok(1), Ok(2), Err("a"), Ok(4)])
+    ///         .filter_ok(|i| i % 2 == 0)
+    ///         .collect(),
+    /// );
+    ///
+    /// assert_eq!(filtered, vec![Ok(2), Err("a"), Ok(4)]);
+    /// ```
+    fn filter_ok<F>(self, _: F) -> FilterOk<Self, F>
+    where
+        F: FnMut(&O) -> bool;
+
+    /// Filter `Err` values while keeping `Ok`
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use resiter::stream::filter::StreamFilter;
+    ///
+    /// let filtered: Vec<Result<usize, &str>> = block_on(
+    ///     stream::iter(vec![Ok(1), Ok(2), Err("a"), Ok(4)])
+    ///         .filter_err(|_| false)
+    ///         .collect(),
+    /// );
+    ///
+    /// assert_eq!(filtered, vec![Ok(1), Ok(2), Ok(4)]);
+    /// ```
+    fn filter_err<F>(self, _: F) -> FilterErr<Self, F>
+    where
+        F: FnMut(&E) -> bool;
+}
+
+impl<S, O, E> StreamFilter<O, E> for S
+where
+    S: Stream<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn filter_ok<F>(self, f: F) -> FilterOk<Self, F>
+    where
+        F: FnMut(&O) -> bool,
+    {
+        FilterOk { stream: self, f }
+    }
+
+    #[inline]
+    fn filter_err<F>(self, f: F) -> FilterErr<Self, F>
+    where
+        F: FnMut(&E) -> bool,
+    {
+        FilterErr { stream: self, f }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Clone)]
+    pub struct FilterOk<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F> fmt::Debug for FilterOk<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterOk")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S, O, E, F> Stream for FilterOk<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(&O) -> bool,
+{
+    type Item = Result<O, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut stream = this.stream;
+        loop {
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(x))) => {
+                    if (this.f)(&x) {
+                        return Poll::Ready(Some(Ok(x)));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Clone)]
+    pub struct FilterErr<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F> fmt::Debug for FilterErr<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterErr")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S, O, E, F> Stream for FilterErr<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(&E) -> bool,
+{
+    type Item = Result<O, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let mut stream = this.stream;
+        loop {
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Err(x))) => {
+                    if (this.f)(&x) {
+                        return Poll::Ready(Some(Err(x)));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
@@ -0,0 +1,162 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Extension trait for `Stream<Item = Result<O, E>>` to selectively transform Oks and Errors.
+pub trait StreamMap<O, E>: Sized {
+    /// Map all `Ok` items while leaving `Err` as is
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use resiter::stream::map::StreamMap;
+    ///
+    /// let mapped: Vec<Result<usize, &str>> = block_on(
+    ///     stream::iter(vec![Ok(1), Err("a"), Ok(3)])
+    ///         .map_ok(|i| 2 * i)
+    ///         .collect(),
+    /// );
+    ///
+    /// assert_eq!(mapped, vec![Ok(2), Err("a"), Ok(6)]);
+    /// ```
+    fn map_ok<F, O2>(self, _: F) -> MapOk<Self, F>
+    where
+        F: FnMut(O) -> O2;
+
+    /// Map all `Err` items while leaving `Ok` as is
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use resiter::stream::map::StreamMap;
+    ///
+    /// let mapped: Vec<Result<usize, usize>> = block_on(
+    ///     stream::iter(vec![Ok(1), Err("a"), Ok(3)])
+    ///         .map_err(|e| e.len())
+    ///         .collect(),
+    /// );
+    ///
+    /// assert_eq!(mapped, vec![Ok(1), Err(1), Ok(3)]);
+    /// ```
+    fn map_err<F, E2>(self, _: F) -> MapErr<Self, F>
+    where
+        F: FnMut(E) -> E2;
+}
+
+impl<S, O, E> StreamMap<O, E> for S
+where
+    S: Stream<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn map_ok<F, O2>(self, f: F) -> MapOk<Self, F>
+    where
+        F: FnMut(O) -> O2,
+    {
+        MapOk { stream: self, f }
+    }
+
+    #[inline]
+    fn map_err<F, E2>(self, f: F) -> MapErr<Self, F>
+    where
+        F: FnMut(E) -> E2,
+    {
+        MapErr { stream: self, f }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Clone)]
+    pub struct MapOk<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F> fmt::Debug for MapOk<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapOk")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S, O, E, F, O2> Stream for MapOk<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+{
+    type Item = Result<O2, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let stream = this.stream;
+        let f = this.f;
+        stream.poll_next(cx).map(|o| o.map(|r| r.map(f)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Clone)]
+    pub struct MapErr<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F> fmt::Debug for MapErr<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapErr")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S, O, E, F, E2> Stream for MapErr<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(E) -> E2,
+{
+    type Item = Result<O, E2>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let stream = this.stream;
+        let f = this.f;
+        stream.poll_next(cx).map(|o| o.map(|r| r.map_err(f)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,68 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions for `Stream<Item = Result<O, E>>`
+//!
+//! Only available with the `stream` feature.
+//!
+//! The modules in here mirror the iterator extensions of this crate, so the same pipeline style
+//! can be used for streams:
+//!
+//! ```
+//! extern crate futures;
+//! extern crate resiter;
+//!
+//! use futures::executor::block_on;
+//! use futures::stream::{self, StreamExt};
+//! use resiter::stream::prelude::*;
+//! use std::str::FromStr;
+//!
+//! let doubles: Vec<usize> = block_on(
+//!     stream::iter(["1", "2", "a", "4", "5"].iter())
+//!         .map(|txt| usize::from_str(txt))
+//!         .map_ok(|i| 2 * i)
+//!         .oks()
+//!         .collect(),
+//! );
+//!
+//! assert_eq!(doubles, vec![2, 4, 8, 10]);
+//! ```
+//!
+//! The traits are named with a `Stream` prefix so they never collide with their iterator
+//! counterparts when both are imported.
+
+pub mod and_then;
+pub mod errors;
+pub mod filter;
+pub mod map;
+pub mod oks;
+pub mod onerr;
+pub mod onok;
+pub mod while_ok;
+
+pub use self::and_then::StreamAndThen;
+pub use self::errors::StreamGetErrors;
+pub use self::filter::StreamFilter;
+pub use self::map::StreamMap;
+pub use self::oks::StreamGetOks;
+pub use self::onerr::StreamOnErrDo;
+pub use self::onok::StreamOnOkDo;
+pub use self::while_ok::StreamWhileOk;
+
+/// Prelude for the stream extensions
+///
+/// Imports all stream extension traits, so you can `use resiter::stream::prelude::*;` in your
+/// crate.
+pub mod prelude {
+    pub use super::and_then::StreamAndThen;
+    pub use super::errors::StreamGetErrors;
+    pub use super::filter::StreamFilter;
+    pub use super::map::StreamMap;
+    pub use super::oks::StreamGetOks;
+    pub use super::onerr::StreamOnErrDo;
+    pub use super::onok::StreamOnOkDo;
+    pub use super::while_ok::StreamWhileOk;
+}
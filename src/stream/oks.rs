@@ -0,0 +1,87 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Extension trait for `Stream<Item = Result<T, E>>` to get all `T`s
+pub trait StreamGetOks<T, E>: Sized {
+    /// Stream every `Ok` while ignoring every `Err`
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use resiter::stream::oks::StreamGetOks;
+    ///
+    /// let oks: Vec<usize> = block_on(
+    ///     stream::iter(vec![Ok(1), Err("a"), Ok(3)])
+    ///         .oks()
+    ///         .collect(),
+    /// );
+    ///
+    /// assert_eq!(oks, vec![1, 3]);
+    /// ```
+    fn oks(self) -> Oks<Self>;
+}
+
+impl<T, E, S> StreamGetOks<T, E> for S
+where
+    S: Stream<Item = Result<T, E>> + Sized,
+{
+    #[inline]
+    fn oks(self) -> Oks<Self> {
+        Oks { stream: self }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Clone)]
+    pub struct Oks<S> {
+        #[pin]
+        stream: S,
+    }
+}
+
+impl<S> fmt::Debug for Oks<S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Oks").field("stream", &self.stream).finish()
+    }
+}
+
+impl<S, T, E> Stream for Oks<S>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut stream = self.project().stream;
+        loop {
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(t))) => return Poll::Ready(Some(t)),
+                Poll::Ready(Some(Err(_))) => {}
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.stream.size_hint().1)
+    }
+}
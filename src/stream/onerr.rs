@@ -0,0 +1,95 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Extension trait for `Stream<Item = Result<O, E>>` to do something on `Err(_)`
+pub trait StreamOnErrDo<O, E>: Sized {
+    /// Apply a sideffect on each `Err`
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use resiter::stream::onerr::StreamOnErrDo;
+    ///
+    /// let mut errs = Vec::new();
+    /// let _: Vec<Result<usize, &str>> = block_on(
+    ///     stream::iter(vec![Ok(1), Err("a"), Ok(3)])
+    ///         .on_err(|e| errs.push(*e))
+    ///         .collect(),
+    /// );
+    ///
+    /// assert_eq!(errs, vec!["a"]);
+    /// ```
+    fn on_err<F>(self, _: F) -> OnErr<Self, F>
+    where
+        F: FnMut(&E);
+}
+
+impl<S, O, E> StreamOnErrDo<O, E> for S
+where
+    S: Stream<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn on_err<F>(self, f: F) -> OnErr<Self, F>
+    where
+        F: FnMut(&E),
+    {
+        OnErr { stream: self, f }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Clone)]
+    pub struct OnErr<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F> fmt::Debug for OnErr<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OnErr")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S, O, E, F> Stream for OnErr<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(&E),
+{
+    type Item = Result<O, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let stream = this.stream;
+        let res = stream.poll_next(cx);
+        if let Poll::Ready(Some(Err(ref e))) = res {
+            (this.f)(e);
+        }
+        res
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,95 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Extension trait for `Stream<Item = Result<O, E>>` to do something on `Ok(_)`
+pub trait StreamOnOkDo<O, E>: Sized {
+    /// Perform a side effect on each Ok value
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream::{self, StreamExt};
+    /// use resiter::stream::onok::StreamOnOkDo;
+    ///
+    /// let mut oks = Vec::new();
+    /// let _: Vec<Result<usize, &str>> = block_on(
+    ///     stream::iter(vec![Ok(1), Err("a"), Ok(3)])
+    ///         .on_ok(|o| oks.push(*o))
+    ///         .collect(),
+    /// );
+    ///
+    /// assert_eq!(oks, vec![1, 3]);
+    /// ```
+    fn on_ok<F>(self, _: F) -> OnOk<Self, F>
+    where
+        F: FnMut(&O);
+}
+
+impl<S, O, E> StreamOnOkDo<O, E> for S
+where
+    S: Stream<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn on_ok<F>(self, f: F) -> OnOk<Self, F>
+    where
+        F: FnMut(&O),
+    {
+        OnOk { stream: self, f }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Clone)]
+    pub struct OnOk<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F> fmt::Debug for OnOk<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OnOk")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S, O, E, F> Stream for OnOk<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(&O),
+{
+    type Item = Result<O, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let stream = this.stream;
+        let res = stream.poll_next(cx);
+        if let Poll::Ready(Some(Ok(ref o))) = res {
+            (this.f)(o);
+        }
+        res
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
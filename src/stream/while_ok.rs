@@ -0,0 +1,96 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+/// Extension trait for `Stream<Item = Result<O, E>>` to iter until an error is encountered.
+pub trait StreamWhileOk<O, E>: Sized {
+    /// Perform an action on each `Ok` value. Stop on first `Err`
+    ///
+    /// The returned future resolves to `Ok(())` when the stream is exhausted, or to the first
+    /// `Err`.
+    ///
+    /// ```
+    /// extern crate futures;
+    /// extern crate resiter;
+    ///
+    /// use futures::executor::block_on;
+    /// use futures::stream;
+    /// use resiter::stream::while_ok::StreamWhileOk;
+    ///
+    /// let mut s = 0;
+    /// let res = block_on(
+    ///     stream::iter(vec![Ok(1), Ok(2), Err("a"), Ok(4)]).while_ok(|i| s += i),
+    /// );
+    ///
+    /// assert_eq!(s, 3);
+    /// assert_eq!(res, Err("a"));
+    /// ```
+    fn while_ok<F>(self, _: F) -> WhileOk<Self, F>
+    where
+        F: FnMut(O);
+}
+
+impl<S, O, E> StreamWhileOk<O, E> for S
+where
+    S: Stream<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn while_ok<F>(self, f: F) -> WhileOk<Self, F>
+    where
+        F: FnMut(O),
+    {
+        WhileOk { stream: self, f }
+    }
+}
+
+pin_project! {
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    #[derive(Clone)]
+    pub struct WhileOk<S, F> {
+        #[pin]
+        stream: S,
+        f: F,
+    }
+}
+
+impl<S, F> fmt::Debug for WhileOk<S, F>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WhileOk")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+impl<S, O, E, F> Future for WhileOk<S, F>
+where
+    S: Stream<Item = Result<O, E>>,
+    F: FnMut(O),
+{
+    type Output = Result<(), E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut stream = this.stream;
+        loop {
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(o))) => (this.f)(o),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Err(e)),
+                Poll::Ready(None) => return Poll::Ready(Ok(())),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::iter::{Product, Sum};
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to sum up or multiply all `Ok` values
+pub trait SumOks<O, E>: Sized {
+    /// Sum up all `Ok` values, stopping at the first `Err`
+    ///
+    /// ```
+    /// use resiter::sum::SumOks;
+    /// use std::str::FromStr;
+    ///
+    /// let sum = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .sum_oks::<usize>();
+    ///
+    /// assert_eq!(sum, Ok(6));
+    ///
+    /// let sum = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .sum_oks::<usize>();
+    ///
+    /// assert!(sum.is_err());
+    /// ```
+    fn sum_oks<S>(self) -> Result<S, E>
+    where
+        S: Sum<O>;
+
+    /// Multiply all `Ok` values, stopping at the first `Err`
+    ///
+    /// ```
+    /// use resiter::sum::SumOks;
+    /// use std::str::FromStr;
+    ///
+    /// let product = ["2", "3", "4"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .product_oks::<usize>();
+    ///
+    /// assert_eq!(product, Ok(24));
+    /// ```
+    fn product_oks<P>(self) -> Result<P, E>
+    where
+        P: Product<O>;
+}
+
+impl<I, O, E> SumOks<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn sum_oks<S>(self) -> Result<S, E>
+    where
+        S: Sum<O>,
+    {
+        self.sum()
+    }
+
+    #[inline]
+    fn product_oks<P>(self) -> Result<P, E>
+    where
+        P: Product<O>,
+    {
+        self.product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum_oks_stops_at_first_error() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mut iter = v.into_iter();
+
+        assert_eq!(iter.by_ref().sum_oks::<usize>(), Err(2));
+        assert_eq!(iter.next(), Some(Ok(3)));
+    }
+}
@@ -0,0 +1,291 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to take items depending on the `Ok` values
+pub trait Take<O, E>: Sized {
+    /// Take `Ok` items while the predicate holds, passing `Err` items through untouched.
+    ///
+    /// The iterator stops at the first `Ok` item for which the predicate returns `false`. That
+    /// item is consumed and dropped.
+    ///
+    /// ```
+    /// use resiter::take::Take;
+    /// use std::str::FromStr;
+    ///
+    /// let taken: Vec<_> = ["1", "2", "a", "3", "10", "4", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .take_while_ok(|i| *i < 5)
+    ///     .collect();
+    ///
+    /// assert_eq!(taken.len(), 4);
+    /// assert_eq!(taken[0], Ok(1));
+    /// assert_eq!(taken[1], Ok(2));
+    /// assert!(taken[2].is_err());
+    /// assert_eq!(taken[3], Ok(3));
+    /// ```
+    fn take_while_ok<P>(self, _: P) -> TakeWhileOk<Self, P>
+    where
+        P: FnMut(&O) -> bool;
+
+    /// Take all items up to and including the first `Err`
+    ///
+    /// After the first `Err` was yielded, the iterator always returns `None`, even if the
+    /// underlying iterator could continue.
+    ///
+    /// ```
+    /// use resiter::take::Take;
+    /// use std::str::FromStr;
+    ///
+    /// let taken: Vec<_> = ["1", "2", "a", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .take_until_err()
+    ///     .collect();
+    ///
+    /// assert_eq!(taken.len(), 3);
+    /// assert_eq!(taken[0], Ok(1));
+    /// assert_eq!(taken[1], Ok(2));
+    /// assert!(taken[2].is_err());
+    /// ```
+    fn take_until_err(self) -> TakeUntilErr<Self>;
+
+    /// Take items until `n` `Ok` values were yielded, passing `Err` items through untouched
+    ///
+    /// Other than `Iterator::take`, errors do not count toward the limit. The iterator stops
+    /// right after the `n`th `Ok` value, so errors following it are not yielded.
+    ///
+    /// ```
+    /// use resiter::take::Take;
+    /// use std::str::FromStr;
+    ///
+    /// let taken: Vec<_> = ["1", "a", "2", "b", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .take_oks(2)
+    ///     .collect();
+    ///
+    /// assert_eq!(taken.len(), 3);
+    /// assert_eq!(taken[0], Ok(1));
+    /// assert!(taken[1].is_err());
+    /// assert_eq!(taken[2], Ok(2));
+    /// ```
+    fn take_oks(self, n: usize) -> TakeOks<Self>;
+}
+
+impl<I, O, E> Take<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn take_while_ok<P>(self, predicate: P) -> TakeWhileOk<Self, P>
+    where
+        P: FnMut(&O) -> bool,
+    {
+        TakeWhileOk {
+            iter: self,
+            predicate,
+            done: false,
+        }
+    }
+
+    #[inline]
+    fn take_until_err(self) -> TakeUntilErr<Self> {
+        TakeUntilErr {
+            iter: self,
+            done: false,
+        }
+    }
+
+    #[inline]
+    fn take_oks(self, n: usize) -> TakeOks<Self> {
+        TakeOks {
+            iter: self,
+            remaining: n,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TakeWhileOk<I, P> {
+    iter: I,
+    predicate: P,
+    done: bool,
+}
+
+impl<I, P> fmt::Debug for TakeWhileOk<I, P>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TakeWhileOk")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, O, E, P> Iterator for TakeWhileOk<I, P>
+where
+    I: Iterator<Item = Result<O, E>>,
+    P: FnMut(&O) -> bool,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.iter.next() {
+            Some(Ok(x)) => {
+                if (self.predicate)(&x) {
+                    Some(Ok(x))
+                } else {
+                    self.done = true;
+                    None
+                }
+            }
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, O, E, P> FusedIterator for TakeWhileOk<I, P>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    P: FnMut(&O) -> bool,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct TakeUntilErr<I> {
+    iter: I,
+    done: bool,
+}
+
+impl<I, O, E> Iterator for TakeUntilErr<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let r = self.iter.next()?;
+        self.done = r.is_err();
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (lo, hi) = self.iter.size_hint();
+            (lo.min(1), hi)
+        }
+    }
+}
+
+impl<I, O, E> FusedIterator for TakeUntilErr<I> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct TakeOks<I> {
+    iter: I,
+    /// Number of `Ok` values which may still be yielded
+    remaining: usize,
+}
+
+impl<I, O, E> Iterator for TakeOks<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let r = self.iter.next()?;
+        if r.is_ok() {
+            self.remaining -= 1;
+        }
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remaining == 0 {
+            (0, Some(0))
+        } else {
+            let (lo, hi) = self.iter.size_hint();
+            (lo.min(self.remaining), hi)
+        }
+    }
+}
+
+impl<I, O, E> FusedIterator for TakeOks<I> where I: FusedIterator<Item = Result<O, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_while_ok_fuses() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(5), Ok(2), Err(3)];
+        let mut iter = v.into_iter().take_while_ok(|i| *i < 5);
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_take_until_err_fuses() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mut iter = v.into_iter().take_until_err();
+        assert_eq!(iter.size_hint(), (1, Some(3)));
+
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.next(), Some(Err(2)));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_take_oks_stops_after_last_ok() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2), Err(3), Ok(4)];
+        let mut iter = v.into_iter().take_oks(1);
+        assert_eq!(iter.size_hint(), (1, Some(4)));
+
+        assert_eq!(iter.next(), Some(Err(1)));
+        assert_eq!(iter.next(), Some(Ok(2)));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+    }
+}
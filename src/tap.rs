@@ -0,0 +1,228 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to modify Oks and Errors in place
+pub trait Tap<O, E>: Sized {
+    /// Call a closure with mutable access to each `Ok` value
+    ///
+    /// ```
+    /// use resiter::tap::Tap;
+    ///
+    /// let trimmed: Vec<Result<String, usize>> = vec![Ok(String::from(" a ")), Err(1)]
+    ///     .into_iter()
+    ///     .tap_ok_mut(|s| s.retain(|c| c != ' '))
+    ///     .collect();
+    ///
+    /// assert_eq!(trimmed, vec![Ok(String::from("a")), Err(1)]);
+    /// ```
+    fn tap_ok_mut<F>(self, _: F) -> TapOkMut<Self, F>
+    where
+        F: FnMut(&mut O);
+
+    /// Call a closure with mutable access to each `Err` value
+    ///
+    /// ```
+    /// use resiter::tap::Tap;
+    ///
+    /// let enriched: Vec<Result<usize, String>> = vec![Ok(1), Err(String::from("failed"))]
+    ///     .into_iter()
+    ///     .tap_err_mut(|e| e.push_str(" while reading"))
+    ///     .collect();
+    ///
+    /// assert_eq!(enriched, vec![Ok(1), Err(String::from("failed while reading"))]);
+    /// ```
+    fn tap_err_mut<F>(self, _: F) -> TapErrMut<Self, F>
+    where
+        F: FnMut(&mut E);
+}
+
+impl<I, O, E> Tap<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn tap_ok_mut<F>(self, f: F) -> TapOkMut<Self, F>
+    where
+        F: FnMut(&mut O),
+    {
+        TapOkMut { iter: self, f }
+    }
+
+    #[inline]
+    fn tap_err_mut<F>(self, f: F) -> TapErrMut<Self, F>
+    where
+        F: FnMut(&mut E),
+    {
+        TapErrMut { iter: self, f }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TapOkMut<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for TapOkMut<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TapOkMut")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F> Iterator for TapOkMut<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut O),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut r = self.iter.next()?;
+        if let Ok(ref mut x) = r {
+            (self.f)(x);
+        }
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, mut r| {
+            if let Ok(ref mut x) = r {
+                f(x);
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for TapOkMut<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&mut O),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mut r = self.iter.next_back()?;
+        if let Ok(ref mut x) = r {
+            (self.f)(x);
+        }
+        Some(r)
+    }
+}
+
+impl<I, O, E, F> FusedIterator for TapOkMut<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&mut O),
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TapErrMut<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for TapErrMut<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TapErrMut")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, O, E, F> Iterator for TapErrMut<I, F>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&mut E),
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut r = self.iter.next()?;
+        if let Err(ref mut x) = r {
+            (self.f)(x);
+        }
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, mut r| {
+            if let Err(ref mut x) = r {
+                f(x);
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for TapErrMut<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&mut E),
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mut r = self.iter.next_back()?;
+        if let Err(ref mut x) = r {
+            (self.f)(x);
+        }
+        Some(r)
+    }
+}
+
+impl<I, O, E, F> FusedIterator for TapErrMut<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&mut E),
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap_ok_mut_fold() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let sum = v
+            .into_iter()
+            .tap_ok_mut(|o| *o *= 10)
+            .fold(0, |acc, r| acc + r.unwrap_or_else(|e| e));
+
+        assert_eq!(sum, 42);
+    }
+}
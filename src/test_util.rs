@@ -0,0 +1,173 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Assertion helpers for tests of code which produces `Iterator<Item = Result<O, E>>`
+//!
+//! Only available with the `test-util` feature. All helpers drain the iterator and panic with a
+//! message listing every offending item together with its zero-based position.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to assert on its `Ok` and `Err` items
+pub trait AssertResults<O, E>: Sized {
+    /// Return all `Ok` values, panicking if there was any `Err`
+    ///
+    /// ```
+    /// use resiter::test_util::AssertResults;
+    /// use std::str::FromStr;
+    ///
+    /// let oks = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .assert_all_ok();
+    ///
+    /// assert_eq!(oks, vec![1, 2, 3]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resiter::test_util::AssertResults;
+    /// use std::str::FromStr;
+    ///
+    /// // panics with "expected no errors, found 1:\n  item 1: ParseIntError { .. }"
+    /// ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .assert_all_ok();
+    /// ```
+    #[track_caller]
+    fn assert_all_ok(self) -> Vec<O>
+    where
+        E: fmt::Debug;
+
+    /// Return all `Ok` and `Err` values, panicking unless there were exactly `expected` errors
+    ///
+    /// ```
+    /// use resiter::test_util::AssertResults;
+    /// use std::str::FromStr;
+    ///
+    /// let (oks, errs) = ["1", "a", "3", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .assert_err_count(2);
+    ///
+    /// assert_eq!(oks, vec![1, 3]);
+    /// assert_eq!(errs.len(), 2);
+    /// ```
+    #[track_caller]
+    fn assert_err_count(self, expected: usize) -> (Vec<O>, Vec<E>)
+    where
+        E: fmt::Debug;
+
+    /// Return all `Err` values, panicking if there was any `Ok`
+    ///
+    /// ```
+    /// use resiter::test_util::AssertResults;
+    /// use std::str::FromStr;
+    ///
+    /// let errs = ["a", "b"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .collect_expect_errs();
+    ///
+    /// assert_eq!(errs.len(), 2);
+    /// ```
+    #[track_caller]
+    fn collect_expect_errs(self) -> Vec<E>
+    where
+        O: fmt::Debug;
+}
+
+impl<I, O, E> AssertResults<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[track_caller]
+    fn assert_all_ok(self) -> Vec<O>
+    where
+        E: fmt::Debug,
+    {
+        let (oks, errs) = partition_indexed(self);
+        if !errs.is_empty() {
+            fail(&format_items("expected no errors", &errs));
+        }
+        oks.into_iter().map(|(_, o)| o).collect()
+    }
+
+    #[track_caller]
+    fn assert_err_count(self, expected: usize) -> (Vec<O>, Vec<E>)
+    where
+        E: fmt::Debug,
+    {
+        let (oks, errs) = partition_indexed(self);
+        if errs.len() != expected {
+            let mut msg = String::new();
+            let noun = if expected == 1 { "error" } else { "errors" };
+            let _ = write!(msg, "expected {} {}", expected, noun);
+            fail(&format_items(&msg, &errs));
+        }
+        (
+            oks.into_iter().map(|(_, o)| o).collect(),
+            errs.into_iter().map(|(_, e)| e).collect(),
+        )
+    }
+
+    #[track_caller]
+    fn collect_expect_errs(self) -> Vec<E>
+    where
+        O: fmt::Debug,
+    {
+        let (oks, errs) = partition_indexed(self);
+        if !oks.is_empty() {
+            fail(&format_items("expected no oks", &oks));
+        }
+        errs.into_iter().map(|(_, e)| e).collect()
+    }
+}
+
+type Indexed<T> = Vec<(usize, T)>;
+
+fn partition_indexed<I, O, E>(iter: I) -> (Indexed<O>, Indexed<E>)
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    let mut oks = Vec::new();
+    let mut errs = Vec::new();
+    for (index, r) in iter.enumerate() {
+        match r {
+            Ok(o) => oks.push((index, o)),
+            Err(e) => errs.push((index, e)),
+        }
+    }
+    (oks, errs)
+}
+
+fn format_items<T: fmt::Debug>(expectation: &str, items: &[(usize, T)]) -> String {
+    let mut msg = String::new();
+    let _ = write!(msg, "{}, found {}:", expectation, items.len());
+    for (index, item) in items {
+        let _ = write!(msg, "\n  item {}: {:?}", index, item);
+    }
+    msg
+}
+
+#[track_caller]
+fn fail(msg: &str) -> ! {
+    panic!("{}", msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "expected 1 error, found 2:\n  item 0: 1\n  item 2: 3")]
+    fn test_assert_err_count_lists_errors() {
+        let v: Vec<Result<usize, usize>> = vec![Err(1), Ok(2), Err(3)];
+        v.into_iter().assert_err_count(1);
+    }
+}
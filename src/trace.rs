@@ -0,0 +1,307 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions to make `Iterator<Item = Result<O, E>>` visible in `tracing` instrumentation
+//!
+//! Only available with the `tracing` feature.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use tracing::{Level, Span};
+
+/// Emit an event at a level which is only known at runtime
+///
+/// The `tracing` macros need a constant level, so dispatch to one callsite per level.
+macro_rules! event_at {
+    ($level:expr, $($args:tt)+) => {
+        match $level {
+            Level::ERROR => ::tracing::event!(Level::ERROR, $($args)+),
+            Level::WARN => ::tracing::event!(Level::WARN, $($args)+),
+            Level::INFO => ::tracing::event!(Level::INFO, $($args)+),
+            Level::DEBUG => ::tracing::event!(Level::DEBUG, $($args)+),
+            Level::TRACE => ::tracing::event!(Level::TRACE, $($args)+),
+        }
+    };
+}
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to emit `tracing` events and spans
+pub trait TraceResults<O, E>: Sized {
+    /// Emit an event with the error as `error` field at `level` for every `Err` item
+    ///
+    /// ```
+    /// extern crate resiter;
+    /// extern crate tracing;
+    ///
+    /// use resiter::trace::TraceResults;
+    /// use std::str::FromStr;
+    /// use tracing::Level;
+    ///
+    /// let sum: usize = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .trace_errs(Level::WARN)
+    ///     .filter_map(Result::ok)
+    ///     .sum();
+    ///
+    /// assert_eq!(sum, 4);
+    /// ```
+    fn trace_errs(self, level: Level) -> TraceErrs<Self>
+    where
+        E: fmt::Debug;
+
+    /// Emit an event with the value as `ok` field at `level` for every `Ok` item
+    ///
+    /// ```
+    /// extern crate resiter;
+    /// extern crate tracing;
+    ///
+    /// use resiter::trace::TraceResults;
+    /// use std::str::FromStr;
+    /// use tracing::Level;
+    ///
+    /// let parsed: Vec<_> = ["1", "2"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .trace_oks(Level::TRACE)
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed, vec![Ok(1), Ok(2)]);
+    /// ```
+    fn trace_oks(self, level: Level) -> TraceOks<Self>
+    where
+        O: fmt::Debug;
+
+    /// Enter `span` around every call to the underlying iterator
+    ///
+    /// Events emitted by earlier, lazily evaluated stages of the pipeline are recorded inside
+    /// of the span.
+    ///
+    /// ```
+    /// extern crate resiter;
+    /// extern crate tracing;
+    ///
+    /// use resiter::trace::TraceResults;
+    /// use std::str::FromStr;
+    /// use tracing::{info_span, Level};
+    ///
+    /// let parsed: Vec<_> = ["1", "a"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .trace_errs(Level::ERROR)
+    ///     .in_span(info_span!("parse_numbers"))
+    ///     .collect();
+    ///
+    /// assert_eq!(parsed.len(), 2);
+    /// ```
+    fn in_span(self, span: Span) -> InSpan<Self>;
+}
+
+impl<I, O, E> TraceResults<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn trace_errs(self, level: Level) -> TraceErrs<Self>
+    where
+        E: fmt::Debug,
+    {
+        TraceErrs { iter: self, level }
+    }
+
+    #[inline]
+    fn trace_oks(self, level: Level) -> TraceOks<Self>
+    where
+        O: fmt::Debug,
+    {
+        TraceOks { iter: self, level }
+    }
+
+    #[inline]
+    fn in_span(self, span: Span) -> InSpan<Self> {
+        InSpan { iter: self, span }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct TraceErrs<I> {
+    iter: I,
+    level: Level,
+}
+
+impl<I, O, E> Iterator for TraceErrs<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: fmt::Debug,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        if let Err(ref e) = r {
+            event_at!(self.level, error = ?e, "iterator yielded an error");
+        }
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let level = self.level;
+        self.iter.fold(init, move |acc, r| {
+            if let Err(ref e) = r {
+                event_at!(level, error = ?e, "iterator yielded an error");
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for TraceErrs<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    E: fmt::Debug,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        if let Err(ref e) = r {
+            event_at!(self.level, error = ?e, "iterator yielded an error");
+        }
+        Some(r)
+    }
+}
+
+impl<I, O, E> FusedIterator for TraceErrs<I>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    E: fmt::Debug,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct TraceOks<I> {
+    iter: I,
+    level: Level,
+}
+
+impl<I, O, E> Iterator for TraceOks<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    O: fmt::Debug,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        if let Ok(ref o) = r {
+            event_at!(self.level, ok = ?o, "iterator yielded a value");
+        }
+        Some(r)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let level = self.level;
+        self.iter.fold(init, move |acc, r| {
+            if let Ok(ref o) = r {
+                event_at!(level, ok = ?o, "iterator yielded a value");
+            }
+            g(acc, r)
+        })
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for TraceOks<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    O: fmt::Debug,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        if let Ok(ref o) = r {
+            event_at!(self.level, ok = ?o, "iterator yielded a value");
+        }
+        Some(r)
+    }
+}
+
+impl<I, O, E> FusedIterator for TraceOks<I>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    O: fmt::Debug,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct InSpan<I> {
+    iter: I,
+    span: Span,
+}
+
+impl<I, O, E> Iterator for InSpan<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+{
+    type Item = Result<O, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let _entered = self.span.enter();
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let span = self.span;
+        // Like `next`, only the upstream iterator runs inside the span, so leave it while `g`
+        // runs
+        let mut entered = Some(span.enter());
+        self.iter.fold(init, |acc, r| {
+            entered = None;
+            let acc = g(acc, r);
+            entered = Some(span.enter());
+            acc
+        })
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for InSpan<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let _entered = self.span.enter();
+        self.iter.next_back()
+    }
+}
+
+impl<I, O, E> FusedIterator for InSpan<I> where I: FusedIterator<Item = Result<O, E>> {}
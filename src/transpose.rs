@@ -0,0 +1,173 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::iter::FusedIterator;
+
+/// Extension trait for doing
+/// `Iterator<Item = Result<Option<T>, E>>`  ->  `Iterator<Item = Option<Result<T, E>>>`
+pub trait TransposeResults<T, E>: Sized {
+    /// Transpose every `Result<Option<T>, E>` item into an `Option<Result<T, E>>`
+    ///
+    /// `Ok(None)` becomes `None`, `Ok(Some(t))` becomes `Some(Ok(t))` and `Err(e)` becomes
+    /// `Some(Err(e))`.
+    ///
+    /// ```
+    /// use resiter::transpose::TransposeResults;
+    ///
+    /// let v: Vec<Result<Option<i32>, &'static str>> = vec![Ok(Some(1)), Ok(None), Err("err")];
+    ///
+    /// let res: Vec<Option<Result<i32, &'static str>>> = v.into_iter().transpose_into().collect();
+    ///
+    /// assert_eq!(res, vec![Some(Ok(1)), None, Some(Err("err"))]);
+    /// ```
+    fn transpose_into(self) -> TransposedResults<Self>;
+}
+
+impl<I, T, E> TransposeResults<T, E> for I
+where
+    I: Iterator<Item = Result<Option<T>, E>> + Sized,
+{
+    #[inline]
+    fn transpose_into(self) -> TransposedResults<Self> {
+        TransposedResults { iter: self }
+    }
+}
+
+/// Extension trait for doing
+/// `Iterator<Item = Option<Result<T, E>>>`  ->  `Iterator<Item = Result<Option<T>, E>>`
+pub trait TransposeOptions<T, E>: Sized {
+    /// Transpose every `Option<Result<T, E>>` item into a `Result<Option<T>, E>`
+    ///
+    /// `None` becomes `Ok(None)`, `Some(Ok(t))` becomes `Ok(Some(t))` and `Some(Err(e))` becomes
+    /// `Err(e)`.
+    ///
+    /// ```
+    /// use resiter::transpose::TransposeOptions;
+    ///
+    /// let v: Vec<Option<Result<i32, &'static str>>> = vec![Some(Ok(1)), None, Some(Err("err"))];
+    ///
+    /// let res: Vec<Result<Option<i32>, &'static str>> = v.into_iter().transpose_into().collect();
+    ///
+    /// assert_eq!(res, vec![Ok(Some(1)), Ok(None), Err("err")]);
+    /// ```
+    fn transpose_into(self) -> TransposedOptions<Self>;
+}
+
+impl<I, T, E> TransposeOptions<T, E> for I
+where
+    I: Iterator<Item = Option<Result<T, E>>> + Sized,
+{
+    #[inline]
+    fn transpose_into(self) -> TransposedOptions<Self> {
+        TransposedOptions { iter: self }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct TransposedResults<I> {
+    iter: I,
+}
+
+impl<I, T, E> Iterator for TransposedResults<I>
+where
+    I: Iterator<Item = Result<Option<T>, E>>,
+{
+    type Item = Option<Result<T, E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Result::transpose)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, r| g(acc, r.transpose()))
+    }
+}
+
+impl<I, T, E> DoubleEndedIterator for TransposedResults<I>
+where
+    I: DoubleEndedIterator<Item = Result<Option<T>, E>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Result::transpose)
+    }
+}
+
+impl<I, T, E> FusedIterator for TransposedResults<I> where
+    I: FusedIterator<Item = Result<Option<T>, E>>
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct TransposedOptions<I> {
+    iter: I,
+}
+
+impl<I, T, E> Iterator for TransposedOptions<I>
+where
+    I: Iterator<Item = Option<Result<T, E>>>,
+{
+    type Item = Result<Option<T>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Option::transpose)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, o| g(acc, o.transpose()))
+    }
+}
+
+impl<I, T, E> DoubleEndedIterator for TransposedOptions<I>
+where
+    I: DoubleEndedIterator<Item = Option<Result<T, E>>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Option::transpose)
+    }
+}
+
+impl<I, T, E> FusedIterator for TransposedOptions<I> where
+    I: FusedIterator<Item = Option<Result<T, E>>>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transpose_into_roundtrip() {
+        let v: Vec<Result<Option<usize>, usize>> = vec![Ok(Some(1)), Ok(None), Err(3)];
+        let res: Vec<_> = v
+            .clone()
+            .into_iter()
+            .transpose_into()
+            .transpose_into()
+            .collect();
+
+        assert_eq!(res, v);
+    }
+}
@@ -4,6 +4,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension for `Iterator<Item = Result<O, E>>` to filter the Ok(_) and leaving the Err(_) as
 /// is, but allowing the filter to return a `Result<bool, E>` itself
 pub trait TryFilter<O, E>: Sized {
@@ -31,6 +34,10 @@ pub trait TryFilter<O, E>: Sized {
     /// Filters every `Err`-value with a function that can return an Err.
     /// Useful when the filter condition uses functions that can fail.
     ///
+    /// `f` has to fail with the error type of the iterator. If it fails with another type which
+    /// converts into it, use [`Filter::filter_err_and_then`](crate::filter::Filter::filter_err_and_then)
+    /// instead.
+    ///
     /// ```
     /// use resiter::try_filter::TryFilter;
     /// use std::num::ParseIntError;
@@ -72,17 +79,41 @@ where
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct TryFilterOk<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for TryFilterOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TryFilterOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct TryFilterErr<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for TryFilterErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TryFilterErr")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, F> Iterator for TryFilterOk<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -108,6 +139,48 @@ where
         let hint_sup = self.iter.size_hint().1;
         (0, hint_sup)
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(x) => match f(&x) {
+                Ok(true) => g(acc, Ok(x)),
+                Ok(false) => acc,
+                Err(e) => g(acc, Err(e)),
+            },
+            other => g(acc, other),
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for TryFilterOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&O) -> Result<bool, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            return match self.iter.next_back() {
+                Some(Ok(x)) => match (self.f)(&x) {
+                    Ok(true) => Some(Ok(x)),
+                    Ok(false) => continue,
+                    Err(e) => Some(Err(e)),
+                },
+                other => other,
+            };
+        }
+    }
+}
+
+impl<I, O, E, F> FusedIterator for TryFilterOk<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&O) -> Result<bool, E>,
+{
 }
 
 impl<I, O, E, F> Iterator for TryFilterErr<I, F>
@@ -135,4 +208,75 @@ where
         let hint_sup = self.iter.size_hint().1;
         (0, hint_sup)
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Err(x) => match f(&x) {
+                Ok(true) => g(acc, Err(x)),
+                Ok(false) => acc,
+                Err(e) => g(acc, Err(e)),
+            },
+            other => g(acc, other),
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for TryFilterErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Result<bool, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            return match self.iter.next_back() {
+                Some(Err(x)) => match (self.f)(&x) {
+                    Ok(true) => Some(Err(x)),
+                    Ok(false) => continue,
+                    Err(e) => Some(Err(e)),
+                },
+                other => other,
+            };
+        }
+    }
+}
+
+impl<I, O, E, F> FusedIterator for TryFilterErr<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&E) -> Result<bool, E>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_filter_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(4)];
+        let filtered: Vec<_> = v
+            .into_iter()
+            .try_filter_ok(|i| if *i < 4 { Ok(i % 2 == 1) } else { Err(*i) })
+            .rev()
+            .collect();
+
+        assert_eq!(filtered, vec![Err(4), Ok(3), Err(2), Ok(1)]);
+    }
+
+    #[test]
+    fn test_try_filter_err_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3), Ok(4)];
+        let filtered: Vec<_> = v
+            .into_iter()
+            .try_filter_err(|i| Ok(i % 2 == 0))
+            .rev()
+            .collect();
+
+        assert_eq!(filtered, vec![Ok(4), Err(2), Ok(1)]);
+    }
 }
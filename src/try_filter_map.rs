@@ -4,6 +4,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform and map Oks and Errors.
 pub trait TryFilterMap<O, E>: Sized {
     /// Equivalent to [Iterator::filter_map] on all `Ok` values.
@@ -124,11 +127,23 @@ where
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct TryFilterMapOk<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for TryFilterMapOk<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TryFilterMapOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, F, O2> Iterator for TryFilterMapOk<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -151,16 +166,70 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(x) => match f(x) {
+                Some(r) => g(acc, r),
+                None => acc,
+            },
+            Err(e) => g(acc, Err(e)),
+        })
+    }
+}
+
+impl<I, O, E, F, O2> DoubleEndedIterator for TryFilterMapOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Option<Result<O2, E>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            return match self.iter.next_back() {
+                Some(Ok(x)) => match (self.f)(x) {
+                    Some(r) => Some(r),
+                    None => continue,
+                },
+                Some(Err(e)) => Some(Err(e)),
+                None => None,
+            };
+        }
     }
 }
 
+impl<I, O, E, F, O2> FusedIterator for TryFilterMapOk<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Option<Result<O2, E>>,
+{
+}
+
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct TryFilterMapErr<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for TryFilterMapErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TryFilterMapErr")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, E2, F> Iterator for TryFilterMapErr<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -183,6 +252,88 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter.fold(init, move |acc, r| match r {
+            Err(x) => match f(x) {
+                Some(r) => g(acc, r),
+                None => acc,
+            },
+            Ok(x) => g(acc, Ok(x)),
+        })
+    }
+}
+
+impl<I, O, E, E2, F> DoubleEndedIterator for TryFilterMapErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Option<Result<O, E2>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            return match self.iter.next_back() {
+                Some(Err(x)) => match (self.f)(x) {
+                    Some(r) => Some(r),
+                    None => continue,
+                },
+                Some(Ok(x)) => Some(Ok(x)),
+                None => None,
+            };
+        }
+    }
+}
+
+impl<I, O, E, E2, F> FusedIterator for TryFilterMapErr<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Option<Result<O, E2>>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_filter_map_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3), Ok(4)];
+        let filtered: Vec<_> = v
+            .into_iter()
+            .try_filter_map_ok(|i| if i % 2 == 0 { Some(Ok(i)) } else { None })
+            .rev()
+            .collect();
+
+        assert_eq!(filtered, vec![Ok(4), Err(2)]);
+    }
+
+    #[test]
+    fn test_try_filter_map_err_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3), Ok(4)];
+        let filtered: Vec<_> = v
+            .into_iter()
+            .try_filter_map_err(|i| if i % 2 == 0 { Some(Err(i)) } else { None })
+            .rev()
+            .collect();
+
+        assert_eq!(filtered, vec![Ok(4), Err(2), Ok(1)]);
+    }
+
+    #[test]
+    fn test_try_filter_map_ok_hint() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let hint = v
+            .into_iter()
+            .try_filter_map_ok(|i| Some(Ok::<_, usize>(i)))
+            .size_hint();
+
+        assert_eq!(hint, (0, Some(3)));
     }
 }
@@ -4,6 +4,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to selectively transform and map Oks and Errors.
 pub trait TryMap<O, E>: Sized {
     /// Equivalent to [Iterator::map] on all `Ok` values.
@@ -145,14 +148,64 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, r| g(acc, r.and_then(&mut f)))
+    }
+}
+
+impl<I, O, E, F, O2> DoubleEndedIterator for TryMapOk<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            Some(Ok(x)) => Some((self.f)(x)),
+            Some(Err(e)) => Some(Err(e)),
+            None => None,
+        }
+    }
+}
+
+impl<I, O, E, F, O2> FusedIterator for TryMapOk<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+{
+}
+
+impl<I, O, E, F, O2> ExactSizeIterator for TryMapOk<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+{
 }
 
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
 pub struct TryMapErr<I, F> {
     iter: I,
     f: F,
 }
 
+impl<I, F> fmt::Debug for TryMapErr<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TryMapErr")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
 impl<I, O, E, E2, F> Iterator for TryMapErr<I, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -172,4 +225,82 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, r| g(acc, r.or_else(&mut f)))
+    }
+}
+
+impl<I, O, E, E2, F> DoubleEndedIterator for TryMapErr<I, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Result<O, E2>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.iter.next_back() {
+            Some(Err(x)) => Some((self.f)(x)),
+            Some(Ok(x)) => Some(Ok(x)),
+            None => None,
+        }
+    }
+}
+
+impl<I, O, E, E2, F> FusedIterator for TryMapErr<I, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Result<O, E2>,
+{
+}
+
+impl<I, O, E, E2, F> ExactSizeIterator for TryMapErr<I, F>
+where
+    I: ExactSizeIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Result<O, E2>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_map_exact_size() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let iter = v
+            .into_iter()
+            .try_map_ok(|i| if i > 1 { Err(i) } else { Ok(i) })
+            .try_map_err(|e| if e > 2 { Err(e) } else { Ok(e) });
+
+        assert_eq!(iter.len(), 3);
+    }
+
+    #[test]
+    fn test_try_map_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let mapped: Vec<_> = v
+            .into_iter()
+            .try_map_ok(|i| if i < 2 { Ok(i) } else { Err(i) })
+            .rev()
+            .collect();
+
+        assert_eq!(mapped, vec![Err(3), Err(2), Ok(1)]);
+    }
+
+    #[test]
+    fn test_try_map_err_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3)];
+        let mapped: Vec<_> = v
+            .into_iter()
+            .try_map_err(|i| if i < 3 { Ok(i) } else { Err(i) })
+            .rev()
+            .collect();
+
+        assert_eq!(mapped, vec![Err(3), Ok(2), Ok(1)]);
+    }
 }
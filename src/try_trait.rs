@@ -0,0 +1,362 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions for iterators over any `core::ops::Try` type
+//!
+//! The rest of this crate is specialized on `Result<O, E>`. The adapters in this module work on
+//! the output of every `Try` type instead, e.g. `Option<T>`, `ControlFlow<B, C>` or
+//! `Result<O, E>`, and pass the residuals through unchanged.
+//!
+//! Only available with the `try_trait` feature, which requires a nightly compiler.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::{ControlFlow, FromResidual, Residual, Try};
+
+/// Extension trait for `Iterator<Item = T>` where `T: Try` to transform the outputs
+pub trait TryOutputs<T>: Sized
+where
+    T: Try,
+{
+    /// Map the output of every item, while leaving residuals as is
+    ///
+    /// ```
+    /// use resiter::try_trait::TryOutputs;
+    ///
+    /// let mapped: Vec<_> = vec![Some(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .map_output(|i| i * 2)
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped, vec![Some(2), None, Some(6)]);
+    /// ```
+    fn map_output<F, O2>(self, _: F) -> MapOutput<Self, F>
+    where
+        F: FnMut(T::Output) -> O2,
+        T::Residual: Residual<O2>;
+
+    /// Filter items by their output, while keeping all residuals
+    ///
+    /// ```
+    /// use resiter::try_trait::TryOutputs;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let filtered: Vec<ControlFlow<&str, usize>> = vec![
+    ///     ControlFlow::Continue(1),
+    ///     ControlFlow::Continue(2),
+    ///     ControlFlow::Break("stop"),
+    /// ]
+    /// .into_iter()
+    /// .filter_output(|i| i % 2 == 0)
+    /// .collect();
+    ///
+    /// assert_eq!(
+    ///     filtered,
+    ///     vec![ControlFlow::Continue(2), ControlFlow::Break("stop")]
+    /// );
+    /// ```
+    fn filter_output<F>(self, _: F) -> FilterOutput<Self, F>
+    where
+        F: FnMut(&T::Output) -> bool;
+
+    /// Yield only the outputs, dropping all residuals
+    ///
+    /// ```
+    /// use resiter::try_trait::TryOutputs;
+    ///
+    /// let outputs: Vec<_> = vec![Ok(1), Err("a"), Ok(3)]
+    ///     .into_iter()
+    ///     .outputs()
+    ///     .collect();
+    ///
+    /// assert_eq!(outputs, vec![1, 3]);
+    /// ```
+    fn outputs(self) -> Outputs<Self>;
+}
+
+impl<I, T> TryOutputs<T> for I
+where
+    I: Iterator<Item = T> + Sized,
+    T: Try,
+{
+    #[inline]
+    fn map_output<F, O2>(self, f: F) -> MapOutput<Self, F>
+    where
+        F: FnMut(T::Output) -> O2,
+        T::Residual: Residual<O2>,
+    {
+        MapOutput { iter: self, f }
+    }
+
+    #[inline]
+    fn filter_output<F>(self, f: F) -> FilterOutput<Self, F>
+    where
+        F: FnMut(&T::Output) -> bool,
+    {
+        FilterOutput { iter: self, f }
+    }
+
+    #[inline]
+    fn outputs(self) -> Outputs<Self> {
+        Outputs { iter: self }
+    }
+}
+
+/// Map the output of `t` with `f` into the `Try` type of the same family
+#[inline]
+fn map_try<T, F, O2>(t: T, f: &mut F) -> <T::Residual as Residual<O2>>::TryType
+where
+    T: Try,
+    F: FnMut(T::Output) -> O2,
+    T::Residual: Residual<O2>,
+{
+    match t.branch() {
+        ControlFlow::Continue(o) => Try::from_output(f(o)),
+        ControlFlow::Break(r) => FromResidual::from_residual(r),
+    }
+}
+
+/// Return `t` if it is a residual or its output matches `f`
+#[inline]
+fn filter_try<T, F>(t: T, f: &mut F) -> Option<T>
+where
+    T: Try,
+    F: FnMut(&T::Output) -> bool,
+{
+    match t.branch() {
+        ControlFlow::Continue(o) => {
+            if f(&o) {
+                Some(T::from_output(o))
+            } else {
+                None
+            }
+        }
+        ControlFlow::Break(r) => Some(T::from_residual(r)),
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapOutput<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for MapOutput<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapOutput")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, T, F, O2> Iterator for MapOutput<I, F>
+where
+    I: Iterator<Item = T>,
+    T: Try,
+    F: FnMut(T::Output) -> O2,
+    T::Residual: Residual<O2>,
+{
+    type Item = <T::Residual as Residual<O2>>::TryType;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter.next().map(|t| map_try(t, f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, t| g(acc, map_try(t, &mut f)))
+    }
+}
+
+impl<I, T, F, O2> DoubleEndedIterator for MapOutput<I, F>
+where
+    I: DoubleEndedIterator<Item = T>,
+    T: Try,
+    F: FnMut(T::Output) -> O2,
+    T::Residual: Residual<O2>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter.next_back().map(|t| map_try(t, f))
+    }
+}
+
+impl<I, T, F, O2> FusedIterator for MapOutput<I, F>
+where
+    I: FusedIterator<Item = T>,
+    T: Try,
+    F: FnMut(T::Output) -> O2,
+    T::Residual: Residual<O2>,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct FilterOutput<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> fmt::Debug for FilterOutput<I, F>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FilterOutput")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, T, F> Iterator for FilterOutput<I, F>
+where
+    I: Iterator<Item = T>,
+    T: Try,
+    F: FnMut(&T::Output) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(t) = filter_try(self.iter.next()?, &mut self.f) {
+                return Some(t);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        self.iter
+            .fold(init, move |acc, t| match filter_try(t, &mut f) {
+                Some(t) => g(acc, t),
+                None => acc,
+            })
+    }
+}
+
+impl<I, T, F> DoubleEndedIterator for FilterOutput<I, F>
+where
+    I: DoubleEndedIterator<Item = T>,
+    T: Try,
+    F: FnMut(&T::Output) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(t) = filter_try(self.iter.next_back()?, &mut self.f) {
+                return Some(t);
+            }
+        }
+    }
+}
+
+impl<I, T, F> FusedIterator for FilterOutput<I, F>
+where
+    I: FusedIterator<Item = T>,
+    T: Try,
+    F: FnMut(&T::Output) -> bool,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Outputs<I> {
+    iter: I,
+}
+
+impl<I, T> Iterator for Outputs<I>
+where
+    I: Iterator<Item = T>,
+    T: Try,
+{
+    type Item = T::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let ControlFlow::Continue(o) = self.iter.next()?.branch() {
+                return Some(o);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint_sup = self.iter.size_hint().1;
+        (0, hint_sup)
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, t| match t.branch() {
+            ControlFlow::Continue(o) => g(acc, o),
+            ControlFlow::Break(_) => acc,
+        })
+    }
+}
+
+impl<I, T> DoubleEndedIterator for Outputs<I>
+where
+    I: DoubleEndedIterator<Item = T>,
+    T: Try,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let ControlFlow::Continue(o) = self.iter.next_back()?.branch() {
+                return Some(o);
+            }
+        }
+    }
+}
+
+impl<I, T> FusedIterator for Outputs<I>
+where
+    I: FusedIterator<Item = T>,
+    T: Try,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_output_result_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Ok(3)];
+        let res: Vec<Result<String, usize>> =
+            v.into_iter().map_output(|i| i.to_string()).rev().collect();
+
+        assert_eq!(res, vec![Ok("3".to_string()), Err(2), Ok("1".to_string())]);
+    }
+}
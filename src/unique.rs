@@ -0,0 +1,300 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions to yield every `Ok` value only once
+//!
+//! Only available with the `std` feature.
+
+use core::fmt;
+use core::hash::Hash;
+use core::iter::FusedIterator;
+
+use std::collections::HashSet;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to remove duplicate `Ok` values
+pub trait Unique<O, E>: Sized {
+    /// Yield every `Ok` value only once, passing all `Err` items through
+    ///
+    /// Every yielded value is cloned into a `HashSet` to remember it.
+    ///
+    /// ```
+    /// use resiter::unique::Unique;
+    /// use std::str::FromStr;
+    ///
+    /// let unique: Vec<_> = ["1", "2", "a", "1", "3", "2"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .unique_ok()
+    ///     .collect();
+    ///
+    /// assert_eq!(unique.len(), 4);
+    /// assert_eq!(unique[0], Ok(1));
+    /// assert_eq!(unique[1], Ok(2));
+    /// assert!(unique[2].is_err());
+    /// assert_eq!(unique[3], Ok(3));
+    /// ```
+    fn unique_ok(self) -> UniqueOk<Self, O>
+    where
+        O: Eq + Hash + Clone;
+
+    /// Yield every `Ok` value whose key was not seen before, passing all `Err` items through
+    ///
+    /// Only the keys are stored, so `O` does not need to implement `Clone`.
+    ///
+    /// ```
+    /// use resiter::unique::Unique;
+    ///
+    /// let unique: Vec<Result<&str, u8>> = vec![Ok("a"), Ok("bb"), Err(1), Ok("cc"), Ok("d")]
+    ///     .into_iter()
+    ///     .unique_ok_by(|s| s.len())
+    ///     .collect();
+    ///
+    /// assert_eq!(unique, vec![Ok("a"), Ok("bb"), Err(1)]);
+    /// ```
+    fn unique_ok_by<F, K>(self, _: F) -> UniqueOkBy<Self, F, K>
+    where
+        F: FnMut(&O) -> K,
+        K: Eq + Hash;
+}
+
+impl<I, O, E> Unique<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn unique_ok(self) -> UniqueOk<Self, O>
+    where
+        O: Eq + Hash + Clone,
+    {
+        UniqueOk {
+            iter: self,
+            seen: HashSet::new(),
+        }
+    }
+
+    #[inline]
+    fn unique_ok_by<F, K>(self, f: F) -> UniqueOkBy<Self, F, K>
+    where
+        F: FnMut(&O) -> K,
+        K: Eq + Hash,
+    {
+        UniqueOkBy {
+            iter: self,
+            f,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+#[inline]
+fn unique_size_hint(empty: bool, (lo, hi): (usize, Option<usize>)) -> (usize, Option<usize>) {
+    if empty {
+        (lo.min(1), hi)
+    } else {
+        (0, hi)
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct UniqueOk<I, O> {
+    iter: I,
+    seen: HashSet<O>,
+}
+
+impl<I, O> fmt::Debug for UniqueOk<I, O>
+where
+    I: fmt::Debug,
+    O: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UniqueOk")
+            .field("iter", &self.iter)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl<I, O, E> Iterator for UniqueOk<I, O>
+where
+    I: Iterator<Item = Result<O, E>>,
+    O: Eq + Hash + Clone,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(o) => {
+                    if !self.seen.contains(&o) {
+                        self.seen.insert(o.clone());
+                        return Some(Ok(o));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        unique_size_hint(self.seen.is_empty(), self.iter.size_hint())
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut seen = self.seen;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(o) => {
+                if !seen.contains(&o) {
+                    seen.insert(o.clone());
+                    g(acc, Ok(o))
+                } else {
+                    acc
+                }
+            }
+            Err(e) => g(acc, Err(e)),
+        })
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for UniqueOk<I, O>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    O: Eq + Hash + Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back()? {
+                Ok(o) => {
+                    if !self.seen.contains(&o) {
+                        self.seen.insert(o.clone());
+                        return Some(Ok(o));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<I, O, E> FusedIterator for UniqueOk<I, O>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    O: Eq + Hash + Clone,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct UniqueOkBy<I, F, K> {
+    iter: I,
+    f: F,
+    seen: HashSet<K>,
+}
+
+impl<I, F, K> fmt::Debug for UniqueOkBy<I, F, K>
+where
+    I: fmt::Debug,
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UniqueOkBy")
+            .field("iter", &self.iter)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl<I, O, E, F, K> Iterator for UniqueOkBy<I, F, K>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(&O) -> K,
+    K: Eq + Hash,
+{
+    type Item = Result<O, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(o) => {
+                    if self.seen.insert((self.f)(&o)) {
+                        return Some(Ok(o));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        unique_size_hint(self.seen.is_empty(), self.iter.size_hint())
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.f;
+        let mut seen = self.seen;
+        self.iter.fold(init, move |acc, r| match r {
+            Ok(o) => {
+                if seen.insert(f(&o)) {
+                    g(acc, Ok(o))
+                } else {
+                    acc
+                }
+            }
+            Err(e) => g(acc, Err(e)),
+        })
+    }
+}
+
+impl<I, O, E, F, K> DoubleEndedIterator for UniqueOkBy<I, F, K>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(&O) -> K,
+    K: Eq + Hash,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back()? {
+                Ok(o) => {
+                    if self.seen.insert((self.f)(&o)) {
+                        return Some(Ok(o));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<I, O, E, F, K> FusedIterator for UniqueOkBy<I, F, K>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(&O) -> K,
+    K: Eq + Hash,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_ok_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(2), Err(1), Ok(1)];
+        let res: Vec<_> = v.into_iter().unique_ok().rev().collect();
+
+        assert_eq!(res, vec![Ok(1), Err(1), Ok(2)]);
+    }
+}
@@ -0,0 +1,279 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! Extensions which turn panics of a mapping closure into errors
+//!
+//! Only available with the `std` feature.
+//!
+//! The closures are run under [`std::panic::catch_unwind`] as if they were `UnwindSafe`: if a
+//! closure panics, any state it captured may be observed in an inconsistent state by the
+//! following calls. The panic hook still runs, so the panic message is printed as usual unless
+//! the hook is replaced.
+
+use alloc::boxed::Box;
+use core::any::Any;
+use core::fmt;
+use core::iter::FusedIterator;
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// The payload of a caught panic, as returned by [`std::panic::catch_unwind`]
+pub type PanicPayload = Box<dyn Any + Send + 'static>;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to map `Ok` values with closures which may
+/// panic
+pub trait CatchUnwind<O, E>: Sized {
+    /// Map all `Ok` items while leaving `Err` as is, converting panics of `f` into errors with
+    /// `on_panic`
+    ///
+    /// ```
+    /// use resiter::unwind::CatchUnwind;
+    ///
+    /// let mapped: Vec<Result<u8, String>> = vec![Ok(1u8), Ok(0), Err("broken".to_string())]
+    ///     .into_iter()
+    ///     .map_ok_catch_unwind(|i| 10 / i, |_| "panicked".to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     mapped,
+    ///     vec![Ok(10), Err("panicked".to_string()), Err("broken".to_string())]
+    /// );
+    /// ```
+    fn map_ok_catch_unwind<F, O2, P>(self, f: F, on_panic: P) -> MapOkCatchUnwind<Self, F, P>
+    where
+        F: FnMut(O) -> O2,
+        P: FnMut(PanicPayload) -> E;
+
+    /// Map all `Ok` items with a fallible closure while leaving `Err` as is, converting panics of
+    /// `f` into errors with `on_panic`
+    ///
+    /// ```
+    /// use resiter::unwind::CatchUnwind;
+    ///
+    /// let mapped: Vec<Result<u8, String>> = vec![Ok(1u8), Ok(0), Ok(200)]
+    ///     .into_iter()
+    ///     .and_then_ok_catch_unwind(
+    ///         |i| (10 / i).checked_mul(i).ok_or_else(|| "overflow".to_string()),
+    ///         |payload| match payload.downcast_ref::<&str>() {
+    ///             Some(msg) => msg.to_string(),
+    ///             None => "panicked".to_string(),
+    ///         },
+    ///     )
+    ///     .collect();
+    ///
+    /// assert_eq!(mapped[0], Ok(10));
+    /// assert_eq!(mapped[1], Err("attempt to divide by zero".to_string()));
+    /// assert_eq!(mapped[2], Ok(0));
+    /// ```
+    fn and_then_ok_catch_unwind<F, O2, P>(
+        self,
+        f: F,
+        on_panic: P,
+    ) -> AndThenOkCatchUnwind<Self, F, P>
+    where
+        F: FnMut(O) -> Result<O2, E>,
+        P: FnMut(PanicPayload) -> E;
+}
+
+impl<I, O, E> CatchUnwind<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn map_ok_catch_unwind<F, O2, P>(self, f: F, on_panic: P) -> MapOkCatchUnwind<Self, F, P>
+    where
+        F: FnMut(O) -> O2,
+        P: FnMut(PanicPayload) -> E,
+    {
+        MapOkCatchUnwind {
+            iter: self,
+            f,
+            on_panic,
+        }
+    }
+
+    #[inline]
+    fn and_then_ok_catch_unwind<F, O2, P>(
+        self,
+        f: F,
+        on_panic: P,
+    ) -> AndThenOkCatchUnwind<Self, F, P>
+    where
+        F: FnMut(O) -> Result<O2, E>,
+        P: FnMut(PanicPayload) -> E,
+    {
+        AndThenOkCatchUnwind {
+            iter: self,
+            f,
+            on_panic,
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapOkCatchUnwind<I, F, P> {
+    iter: I,
+    f: F,
+    on_panic: P,
+}
+
+impl<I, F, P> fmt::Debug for MapOkCatchUnwind<I, F, P>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MapOkCatchUnwind")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F, P> MapOkCatchUnwind<I, F, P> {
+    #[inline]
+    fn map<O, E, O2>(&mut self, r: Result<O, E>) -> Result<O2, E>
+    where
+        F: FnMut(O) -> O2,
+        P: FnMut(PanicPayload) -> E,
+    {
+        let f = &mut self.f;
+        let o = r?;
+        catch_unwind(AssertUnwindSafe(|| f(o))).map_err(&mut self.on_panic)
+    }
+}
+
+impl<I, O, E, O2, F, P> Iterator for MapOkCatchUnwind<I, F, P>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+    P: FnMut(PanicPayload) -> E,
+{
+    type Item = Result<O2, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        Some(self.map(r))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, O, E, O2, F, P> DoubleEndedIterator for MapOkCatchUnwind<I, F, P>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+    P: FnMut(PanicPayload) -> E,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        Some(self.map(r))
+    }
+}
+
+impl<I, O, E, O2, F, P> FusedIterator for MapOkCatchUnwind<I, F, P>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> O2,
+    P: FnMut(PanicPayload) -> E,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AndThenOkCatchUnwind<I, F, P> {
+    iter: I,
+    f: F,
+    on_panic: P,
+}
+
+impl<I, F, P> fmt::Debug for AndThenOkCatchUnwind<I, F, P>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AndThenOkCatchUnwind")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F, P> AndThenOkCatchUnwind<I, F, P> {
+    #[inline]
+    fn map<O, E, O2>(&mut self, r: Result<O, E>) -> Result<O2, E>
+    where
+        F: FnMut(O) -> Result<O2, E>,
+        P: FnMut(PanicPayload) -> E,
+    {
+        let f = &mut self.f;
+        let o = r?;
+        catch_unwind(AssertUnwindSafe(|| f(o))).unwrap_or_else(|p| Err((self.on_panic)(p)))
+    }
+}
+
+impl<I, O, E, O2, F, P> Iterator for AndThenOkCatchUnwind<I, F, P>
+where
+    I: Iterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+    P: FnMut(PanicPayload) -> E,
+{
+    type Item = Result<O2, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        Some(self.map(r))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, O, E, O2, F, P> DoubleEndedIterator for AndThenOkCatchUnwind<I, F, P>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+    P: FnMut(PanicPayload) -> E,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next_back()?;
+        Some(self.map(r))
+    }
+}
+
+impl<I, O, E, O2, F, P> FusedIterator for AndThenOkCatchUnwind<I, F, P>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(O) -> Result<O2, E>,
+    P: FnMut(PanicPayload) -> E,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_ok_catch_unwind_continues_after_panic() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(2), Ok(3)];
+        let res: Vec<_> = v
+            .into_iter()
+            .map_ok_catch_unwind(
+                |i| {
+                    assert!(i != 2, "two is not allowed");
+                    i * 10
+                },
+                |_| 0,
+            )
+            .rev()
+            .collect();
+
+        assert_eq!(res, vec![Ok(30), Err(0), Ok(10)]);
+    }
+}
@@ -4,6 +4,9 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::fmt;
+use core::iter::FusedIterator;
+
 /// Extension trait for `Iterator<Item = Result<T, E>>` to unwrap everything.
 ///
 /// Errors can be unwraped as well. If the closure `F` returns `Some(O)`, that `O` will be inserted
@@ -17,6 +20,26 @@ where
     I: Iterator<Item = Result<O, E>>,
     F: FnMut(E) -> Option<O>;
 
+impl<I, O, E, F> Clone for UnwrapWith<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>> + Clone,
+    F: FnMut(E) -> Option<O> + Clone,
+{
+    fn clone(&self) -> Self {
+        UnwrapWith(self.0.clone(), self.1.clone())
+    }
+}
+
+impl<I, O, E, F> fmt::Debug for UnwrapWith<I, O, E, F>
+where
+    I: Iterator<Item = Result<O, E>> + fmt::Debug,
+    F: FnMut(E) -> Option<O>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("UnwrapWith").field(&self.0).finish()
+    }
+}
+
 impl<I, O, E, F> Iterator for UnwrapWith<I, O, E, F>
 where
     I: Iterator<Item = Result<O, E>>,
@@ -38,6 +61,49 @@ where
 
         None
     }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let mut f = self.1;
+        self.0.fold(init, move |acc, r| match r {
+            Ok(t) => g(acc, t),
+            Err(e) => match f(e) {
+                Some(t) => g(acc, t),
+                None => acc,
+            },
+        })
+    }
+}
+
+impl<I, O, E, F> DoubleEndedIterator for UnwrapWith<I, O, E, F>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Option<O>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(o) = self.0.next_back() {
+            match o {
+                Ok(t) => return Some(t),
+                Err(e) => {
+                    if let Some(t) = (self.1)(e) {
+                        return Some(t);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<I, O, E, F> FusedIterator for UnwrapWith<I, O, E, F>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    F: FnMut(E) -> Option<O>,
+{
 }
 
 pub trait UnwrapWithExt<I, O, E, F>
@@ -87,3 +153,300 @@ where
         UnwrapWith(self, f)
     }
 }
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to unwrap everything, panicking on errors
+pub trait UnwrapAll<O, E>: Sized {
+    /// Yield the `Ok` values, panicking on the first `Err`
+    ///
+    /// The panic message contains `msg`, the index of the failing item and the `Debug` output of
+    /// the error.
+    ///
+    /// ```
+    /// use resiter::unwrap::UnwrapAll;
+    /// use std::str::FromStr;
+    ///
+    /// let unwrapped: Vec<usize> = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|e| usize::from_str(e))
+    ///     .expect_all("parsing fixtures")
+    ///     .collect();
+    ///
+    /// assert_eq!(unwrapped, vec![1, 2, 3]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resiter::unwrap::UnwrapAll;
+    /// use std::str::FromStr;
+    ///
+    /// // panics with "parsing fixtures: item 1 is an error: ParseIntError { kind: InvalidDigit }"
+    /// let _: Vec<usize> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|e| usize::from_str(e))
+    ///     .expect_all("parsing fixtures")
+    ///     .collect();
+    /// ```
+    fn expect_all(self, msg: &str) -> ExpectAll<'_, Self>
+    where
+        E: fmt::Debug;
+
+    /// Yield the `Ok` values, panicking on the first `Err` like `Result::unwrap`
+    ///
+    /// ```
+    /// use resiter::unwrap::UnwrapAll;
+    /// use std::str::FromStr;
+    ///
+    /// let unwrapped: Vec<usize> = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|e| usize::from_str(e))
+    ///     .unwrap_all()
+    ///     .collect();
+    ///
+    /// assert_eq!(unwrapped, vec![1, 2, 3]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use resiter::unwrap::UnwrapAll;
+    /// use std::str::FromStr;
+    ///
+    /// let _: Vec<usize> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|e| usize::from_str(e))
+    ///     .unwrap_all()
+    ///     .collect();
+    /// ```
+    fn unwrap_all(self) -> Unwrapped<Self>
+    where
+        E: fmt::Debug;
+
+    /// Yield the `Ok` values, replacing every `Err` with `O::default()`
+    ///
+    /// ```
+    /// use resiter::unwrap::UnwrapAll;
+    /// use std::str::FromStr;
+    ///
+    /// let unwrapped: Vec<usize> = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|e| usize::from_str(e))
+    ///     .unwrap_or_default_all()
+    ///     .collect();
+    ///
+    /// assert_eq!(unwrapped, vec![1, 0, 3]);
+    /// ```
+    fn unwrap_or_default_all(self) -> UnwrapOrDefault<Self>
+    where
+        O: Default;
+}
+
+impl<I, O, E> UnwrapAll<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn expect_all(self, msg: &str) -> ExpectAll<'_, Self>
+    where
+        E: fmt::Debug,
+    {
+        ExpectAll {
+            iter: self,
+            msg,
+            index: 0,
+        }
+    }
+
+    #[inline]
+    fn unwrap_all(self) -> Unwrapped<Self>
+    where
+        E: fmt::Debug,
+    {
+        Unwrapped { iter: self }
+    }
+
+    #[inline]
+    fn unwrap_or_default_all(self) -> UnwrapOrDefault<Self>
+    where
+        O: Default,
+    {
+        UnwrapOrDefault { iter: self }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ExpectAll<'a, I> {
+    iter: I,
+    msg: &'a str,
+    /// Index of the next item
+    index: usize,
+}
+
+fn expect_failed<E: fmt::Debug>(msg: &str, index: usize, e: E) -> ! {
+    panic!("{}: item {} is an error: {:?}", msg, index, e)
+}
+
+impl<'a, I, O, E> Iterator for ExpectAll<'a, I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: fmt::Debug,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let r = self.iter.next()?;
+        let index = self.index;
+        self.index += 1;
+        match r {
+            Ok(o) => Some(o),
+            Err(e) => expect_failed(self.msg, index, e),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let msg = self.msg;
+        let mut index = self.index;
+        self.iter.fold(init, move |acc, r| {
+            let i = index;
+            index += 1;
+            match r {
+                Ok(o) => g(acc, o),
+                Err(e) => expect_failed(msg, i, e),
+            }
+        })
+    }
+}
+
+impl<'a, I, O, E> FusedIterator for ExpectAll<'a, I>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    E: fmt::Debug,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct Unwrapped<I> {
+    iter: I,
+}
+
+impl<I, O, E> Iterator for Unwrapped<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    E: fmt::Debug,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Result::unwrap)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter.fold(init, move |acc, r| g(acc, r.unwrap()))
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for Unwrapped<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    E: fmt::Debug,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Result::unwrap)
+    }
+}
+
+impl<I, O, E> FusedIterator for Unwrapped<I>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    E: fmt::Debug,
+{
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct UnwrapOrDefault<I> {
+    iter: I,
+}
+
+impl<I, O, E> Iterator for UnwrapOrDefault<I>
+where
+    I: Iterator<Item = Result<O, E>>,
+    O: Default,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Result::unwrap_or_default)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn fold<Acc, G>(self, init: Acc, mut g: G) -> Acc
+    where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.iter
+            .fold(init, move |acc, r| g(acc, r.unwrap_or_default()))
+    }
+}
+
+impl<I, O, E> DoubleEndedIterator for UnwrapOrDefault<I>
+where
+    I: DoubleEndedIterator<Item = Result<O, E>>,
+    O: Default,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Result::unwrap_or_default)
+    }
+}
+
+impl<I, O, E> FusedIterator for UnwrapOrDefault<I>
+where
+    I: FusedIterator<Item = Result<O, E>>,
+    O: Default,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unwrap_with_rev() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Err(2), Err(3), Ok(4)];
+        let res: Vec<_> = v
+            .into_iter()
+            .unwrap_with(|e| if e == 2 { Some(20) } else { None })
+            .rev()
+            .collect();
+
+        assert_eq!(res, vec![4, 20, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "reading input: item 2 is an error: 7")]
+    fn test_expect_all_reports_index() {
+        let v: Vec<Result<usize, usize>> = vec![Ok(1), Ok(2), Err(7), Ok(4)];
+        let _ = v.into_iter().expect_all("reading input").count();
+    }
+}
@@ -0,0 +1,102 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Extension trait for `Iterator<Item = Result<(A, B), E>>` to unzip the `Ok` values
+pub trait UnzipOks<A, B, E>: Sized {
+    /// Unzip all `Ok` pairs into two collections, stopping at the first `Err`
+    ///
+    /// This is the `Result` counterpart of `Iterator::unzip`.
+    ///
+    /// ```
+    /// use resiter::unzip::UnzipOks;
+    ///
+    /// let res: Result<(Vec<_>, Vec<_>), &str> = vec![Ok((1, 'a')), Ok((2, 'b'))]
+    ///     .into_iter()
+    ///     .unzip_oks();
+    ///
+    /// assert_eq!(res, Ok((vec![1, 2], vec!['a', 'b'])));
+    ///
+    /// let res: Result<(Vec<_>, Vec<_>), &str> = vec![Ok((1, 'a')), Err("bad"), Ok((2, 'b'))]
+    ///     .into_iter()
+    ///     .unzip_oks();
+    ///
+    /// assert_eq!(res, Err("bad"));
+    /// ```
+    fn unzip_oks<FromA, FromB>(self) -> Result<(FromA, FromB), E>
+    where
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>;
+
+    /// Unzip all `Ok` pairs into two collections, or return all `Err` values if there was at
+    /// least one error
+    ///
+    /// Only available with the `alloc` feature.
+    ///
+    /// ```
+    /// use resiter::unzip::UnzipOks;
+    ///
+    /// let res: Result<(Vec<_>, Vec<_>), _> = vec![Ok((1, 'a')), Err("bad"), Err("worse")]
+    ///     .into_iter()
+    ///     .unzip_oks_all_errors();
+    ///
+    /// assert_eq!(res, Err(vec!["bad", "worse"]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn unzip_oks_all_errors<FromA, FromB>(self) -> Result<(FromA, FromB), Vec<E>>
+    where
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>;
+}
+
+impl<I, A, B, E> UnzipOks<A, B, E> for I
+where
+    I: Iterator<Item = Result<(A, B), E>> + Sized,
+{
+    fn unzip_oks<FromA, FromB>(self) -> Result<(FromA, FromB), E>
+    where
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>,
+    {
+        let mut a = FromA::default();
+        let mut b = FromB::default();
+        for res in self {
+            let (x, y) = res?;
+            a.extend(Some(x));
+            b.extend(Some(y));
+        }
+        Ok((a, b))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn unzip_oks_all_errors<FromA, FromB>(self) -> Result<(FromA, FromB), Vec<E>>
+    where
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>,
+    {
+        let mut a = FromA::default();
+        let mut b = FromB::default();
+        let mut errs = Vec::new();
+        for res in self {
+            match res {
+                Ok((x, y)) if errs.is_empty() => {
+                    a.extend(Some(x));
+                    b.extend(Some(y));
+                }
+                Ok(_) => {}
+                Err(e) => errs.push(e),
+            }
+        }
+
+        if errs.is_empty() {
+            Ok((a, b))
+        } else {
+            Err(errs)
+        }
+    }
+}
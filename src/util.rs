@@ -4,6 +4,8 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::iter::FromIterator;
+
 pub trait GetErr<T> {
     fn get_err(self) -> Option<T>;
 }
@@ -26,21 +28,125 @@ impl<T, E> GetOk<T> for Result<T, E> {
 
 /// Extend any Iterator with a `process` method, equivalent to a fallible for_each.
 pub trait Process<T> {
+    /// Call `f` on every element, stopping at the first `Err`, and return `R::default()`
+    #[deprecated(
+        since = "0.6.0",
+        note = "the values produced by `f` are discarded, use `process_last` or `process_collect` instead"
+    )]
     fn process<R: Default, E, F>(self, f: F) -> Result<R, E>
     where
-        F: Fn(T) -> Result<R, E>;
+        F: FnMut(T) -> Result<R, E>;
+
+    /// Call `f` on every element, stopping at the first `Err`, and return the last value it
+    /// produced
+    ///
+    /// ```
+    /// use resiter::Process;
+    ///
+    /// let mut total = 0;
+    /// let last = vec![1, 2, 3].into_iter().process_last(|i| {
+    ///     total += i;
+    ///     Ok::<_, ()>(total)
+    /// });
+    ///
+    /// assert_eq!(last, Ok(Some(6)));
+    /// ```
+    fn process_last<R, E, F>(self, f: F) -> Result<Option<R>, E>
+    where
+        F: FnMut(T) -> Result<R, E>;
+
+    /// Call `f` on every element, stopping at the first `Err`, and collect all values it produced
+    ///
+    /// ```
+    /// use resiter::Process;
+    ///
+    /// let doubled: Result<Vec<_>, String> = vec![1, 2, 3]
+    ///     .into_iter()
+    ///     .process_collect(|i| Ok(i * 2));
+    /// assert_eq!(doubled, Ok(vec![2, 4, 6]));
+    ///
+    /// let mut seen = 0;
+    /// let failed: Result<Vec<_>, String> = vec![1, 2, 3].into_iter().process_collect(|i| {
+    ///     seen += 1;
+    ///     if i == 2 { Err(format!("{} is even", i)) } else { Ok(i) }
+    /// });
+    /// assert_eq!(failed, Err("2 is even".to_string()));
+    /// assert_eq!(seen, 2);
+    /// ```
+    fn process_collect<C, R, E, F>(self, f: F) -> Result<C, E>
+    where
+        F: FnMut(T) -> Result<R, E>,
+        C: FromIterator<R>;
 }
 
 impl<I: Iterator> Process<I::Item> for I {
     /// Process all errors with a lambda
     #[inline]
-    fn process<R: Default, E, F>(self, f: F) -> Result<R, E>
+    fn process<R: Default, E, F>(self, mut f: F) -> Result<R, E>
     where
-        F: Fn(I::Item) -> Result<R, E>,
+        F: FnMut(I::Item) -> Result<R, E>,
     {
         for element in self {
             let _ = f(element)?;
         }
         Ok(R::default())
     }
+
+    #[inline]
+    fn process_last<R, E, F>(self, mut f: F) -> Result<Option<R>, E>
+    where
+        F: FnMut(I::Item) -> Result<R, E>,
+    {
+        let mut last = None;
+        for element in self {
+            last = Some(f(element)?);
+        }
+        Ok(last)
+    }
+
+    #[inline]
+    fn process_collect<C, R, E, F>(self, f: F) -> Result<C, E>
+    where
+        F: FnMut(I::Item) -> Result<R, E>,
+        C: FromIterator<R>,
+    {
+        self.map(f).collect()
+    }
+}
+
+/// Apply `f` to the value in `opt`, clearing `opt` if `f` returns `None`
+///
+/// Used by the flattening adapters so that exhausted inner iterators are never polled again.
+#[inline]
+pub(crate) fn and_then_or_clear<T, U, F>(opt: &mut Option<T>, f: F) -> Option<U>
+where
+    F: FnOnce(&mut T) -> Option<U>,
+{
+    let x = f(opt.as_mut()?);
+    if x.is_none() {
+        *opt = None;
+    }
+    x
+}
+
+/// Compute the `size_hint` of a flattening adapter from its buffered inner iterators
+///
+/// Every item of the outer iterator may expand into any number of items, so an upper bound is
+/// only known once the outer iterator is exhausted.
+#[inline]
+pub(crate) fn flatten_size_hint<T>(
+    frontiter: Option<&T>,
+    backiter: Option<&T>,
+    iter: (usize, Option<usize>),
+) -> (usize, Option<usize>)
+where
+    T: Iterator,
+{
+    let (flo, fhi) = frontiter.map_or((0, Some(0)), Iterator::size_hint);
+    let (blo, bhi) = backiter.map_or((0, Some(0)), Iterator::size_hint);
+    let lo = flo.saturating_add(blo);
+    match (fhi, bhi, iter) {
+        (Some(a), Some(b), (0, Some(0))) => (lo, a.checked_add(b)),
+        _ => (lo, None),
+    }
 }
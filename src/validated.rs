@@ -0,0 +1,91 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+//! A collector type which accumulates all errors.
+//!
+//! Only available with the `alloc` feature.
+
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+use collect::CollectResults;
+
+/// The result of validating all items of an `Iterator<Item = Result<O, E>>`
+///
+/// Other than `Result<Vec<O>, E>`, collecting into a `Validated` does not stop at the first
+/// error, but accumulates every error.
+///
+/// ```
+/// use resiter::validated::Validated;
+/// use std::str::FromStr;
+///
+/// let v: Validated<usize, _> = ["1", "2", "a", "4", "b"]
+///     .iter()
+///     .map(|txt| usize::from_str(txt))
+///     .collect();
+///
+/// assert!(v.is_invalid());
+/// assert_eq!(v.into_result().unwrap_err().len(), 2);
+///
+/// let v: Validated<usize, std::num::ParseIntError> = ["1", "2", "3"]
+///     .iter()
+///     .map(|txt| usize::from_str(txt))
+///     .collect();
+///
+/// assert_eq!(v, Validated::Valid(vec![1, 2, 3]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Validated<O, E> {
+    /// All items were `Ok`
+    Valid(Vec<O>),
+
+    /// At least one item was an `Err`, contains all errors
+    Invalid(Vec<E>),
+}
+
+impl<O, E> Validated<O, E> {
+    /// Returns `true` if no error was encountered
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Validated::Valid(_))
+    }
+
+    /// Returns `true` if at least one error was encountered
+    pub fn is_invalid(&self) -> bool {
+        !self.is_valid()
+    }
+
+    /// Convert into `Ok` with all values or `Err` with all errors
+    pub fn into_result(self) -> Result<Vec<O>, Vec<E>> {
+        self.into()
+    }
+}
+
+impl<O, E> FromIterator<Result<O, E>> for Validated<O, E> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Result<O, E>>,
+    {
+        iter.into_iter().collect_all_errors().into()
+    }
+}
+
+impl<O, E> From<Result<Vec<O>, Vec<E>>> for Validated<O, E> {
+    fn from(res: Result<Vec<O>, Vec<E>>) -> Self {
+        match res {
+            Ok(oks) => Validated::Valid(oks),
+            Err(errs) => Validated::Invalid(errs),
+        }
+    }
+}
+
+impl<O, E> From<Validated<O, E>> for Result<Vec<O>, Vec<E>> {
+    fn from(v: Validated<O, E>) -> Self {
+        match v {
+            Validated::Valid(oks) => Ok(oks),
+            Validated::Invalid(errs) => Err(errs),
+        }
+    }
+}
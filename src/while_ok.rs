@@ -4,6 +4,10 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 
+use core::ops::ControlFlow;
+
+use fold::FoldOk;
+
 /// Extension trait for `Iterator<Item = Result<O, E>>` to iter until an error is encountered.
 pub trait WhileOk<O, E> {
     /// Perform an on each `Ok` value. Stop on first `Err`
@@ -40,6 +44,67 @@ pub trait WhileOk<O, E> {
     fn while_ok<F>(self, _: F) -> Result<(), E>
     where
         F: FnMut(O);
+
+    /// Thread an accumulator through all `Ok` values. Stop on first `Err`
+    ///
+    /// This is the same as [`FoldOk::fold_ok`].
+    ///
+    /// ```
+    /// use resiter::while_ok::WhileOk;
+    /// use std::str::FromStr;
+    ///
+    /// let res = ["1", "2", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .while_ok_fold(Vec::new(), |mut acc, i| {
+    ///         acc.push(i * 2);
+    ///         acc
+    ///     });
+    ///
+    /// assert_eq!(res, Ok(vec![2, 4, 6]));
+    ///
+    /// let res = ["1", "a", "3"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .while_ok_fold(0, |acc, i| acc + i);
+    ///
+    /// assert!(res.is_err());
+    /// ```
+    fn while_ok_fold<Acc, F>(self, init: Acc, _: F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, O) -> Acc;
+
+    /// Perform an action on each `Ok` value until it returns `ControlFlow::Break`. Stop on first
+    /// `Err`
+    ///
+    /// Returns `Ok(ControlFlow::Break(_))` if the action stopped the iteration early and
+    /// `Ok(ControlFlow::Continue(()))` if the iterator was exhausted.
+    ///
+    /// ```
+    /// use resiter::while_ok::WhileOk;
+    /// use std::ops::ControlFlow;
+    /// use std::str::FromStr;
+    ///
+    /// let mut s = 0;
+    ///
+    /// let res = ["1", "2", "3", "a"]
+    ///     .iter()
+    ///     .map(|txt| usize::from_str(txt))
+    ///     .while_ok_control(|i| {
+    ///         s += i;
+    ///         if s > 2 {
+    ///             ControlFlow::Break(i)
+    ///         } else {
+    ///             ControlFlow::Continue(())
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(res, Ok(ControlFlow::Break(2)));
+    /// assert_eq!(s, 3);
+    /// ```
+    fn while_ok_control<B, F>(self, _: F) -> Result<ControlFlow<B>, E>
+    where
+        F: FnMut(O) -> ControlFlow<B>;
 }
 
 impl<I, O, E> WhileOk<O, E> for I
@@ -56,4 +121,25 @@ where
         }
         Ok(())
     }
+
+    #[inline]
+    fn while_ok_fold<Acc, F>(self, init: Acc, f: F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, O) -> Acc,
+    {
+        self.fold_ok(init, f)
+    }
+
+    #[inline]
+    fn while_ok_control<B, F>(self, mut f: F) -> Result<ControlFlow<B>, E>
+    where
+        F: FnMut(O) -> ControlFlow<B>,
+    {
+        for res in self {
+            if let ControlFlow::Break(b) = f(res?) {
+                return Ok(ControlFlow::Break(b));
+            }
+        }
+        Ok(ControlFlow::Continue(()))
+    }
 }
@@ -0,0 +1,111 @@
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+
+use core::cmp;
+use core::iter::FusedIterator;
+
+/// Extension trait for `Iterator<Item = Result<O, E>>` to pair up the values of two iterators
+pub trait Zip<O, E>: Sized {
+    /// Zip two iterators over `Result`s, pairing up the `Ok` values
+    ///
+    /// If either side yields an `Err`, it is forwarded and the item from the other side is
+    /// dropped. If both sides yield an `Err`, the one of `self` is forwarded. Like
+    /// `Iterator::zip`, the iterator stops as soon as either side is exhausted.
+    ///
+    /// ```
+    /// use resiter::zip::Zip;
+    /// use std::str::FromStr;
+    ///
+    /// let names = vec![Ok("a"), Ok("b"), Ok("c")];
+    /// let ids = ["1", "x", "3"].iter().map(|txt| usize::from_str(txt));
+    ///
+    /// let zipped: Vec<_> = ids.zip_ok(names).collect();
+    ///
+    /// assert_eq!(zipped.len(), 3);
+    /// assert_eq!(zipped[0], Ok((1, "a")));
+    /// assert!(zipped[1].is_err());
+    /// assert_eq!(zipped[2], Ok((3, "c")));
+    /// ```
+    fn zip_ok<U, O2>(self, _: U) -> ZipOk<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = Result<O2, E>>;
+}
+
+impl<I, O, E> Zip<O, E> for I
+where
+    I: Iterator<Item = Result<O, E>> + Sized,
+{
+    #[inline]
+    fn zip_ok<U, O2>(self, other: U) -> ZipOk<Self, U::IntoIter>
+    where
+        U: IntoIterator<Item = Result<O2, E>>,
+    {
+        ZipOk {
+            a: self,
+            b: other.into_iter(),
+        }
+    }
+}
+
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct ZipOk<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B, O, O2, E> Iterator for ZipOk<A, B>
+where
+    A: Iterator<Item = Result<O, E>>,
+    B: Iterator<Item = Result<O2, E>>,
+{
+    type Item = Result<(O, O2), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.a.next()?;
+        let b = self.b.next()?;
+        Some(match (a, b) {
+            (Ok(a), Ok(b)) => Ok((a, b)),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lo, a_hi) = self.a.size_hint();
+        let (b_lo, b_hi) = self.b.size_hint();
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => Some(cmp::min(a, b)),
+            (a, None) => a,
+            (None, b) => b,
+        };
+        (cmp::min(a_lo, b_lo), hi)
+    }
+}
+
+impl<A, B, O, O2, E> FusedIterator for ZipOk<A, B>
+where
+    A: FusedIterator<Item = Result<O, E>>,
+    B: FusedIterator<Item = Result<O2, E>>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_ok_left_error_wins() {
+        let a: Vec<Result<usize, &str>> = vec![Err("left"), Ok(2)];
+        let b: Vec<Result<usize, &str>> = vec![Err("right"), Err("right"), Ok(3)];
+        let mut iter = a.into_iter().zip_ok(b);
+
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next(), Some(Err("left")));
+        assert_eq!(iter.next(), Some(Err("right")));
+        assert_eq!(iter.next(), None);
+    }
+}